@@ -6,6 +6,11 @@ impl Component for BladeSawSprite {
     type Storage = DenseVecStorage<Self>;
 }
 
+pub struct BladeSawHazard;
+impl Component for BladeSawHazard {
+    type Storage = DenseVecStorage<Self>;
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BladeSaw {
     pub direction_x: f32,
@@ -10,6 +10,7 @@ impl Component for Bonus {
     type Storage = DenseVecStorage<Self>;
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BonusKind {
     Fuel,
     Wrench,
@@ -0,0 +1,31 @@
+use crate::resources::camera_effects::CameraSettings;
+use amethyst::core::ecs::{Component, DenseVecStorage};
+
+/// Tuning of the level camera. The deadzone is the half size, in pixels,
+/// of the box the ship can move in without dragging the camera along.
+/// `position` is the followed point, before any camera effect is applied.
+pub struct CameraFollow {
+    pub deadzone_x: f32,
+    pub deadzone_y: f32,
+    pub damping: f32,
+    pub look_ahead: f32,
+    pub max_look_ahead: f32,
+    pub position: Option<(f32, f32)>,
+}
+
+impl CameraFollow {
+    pub fn new(settings: &CameraSettings) -> CameraFollow {
+        CameraFollow {
+            deadzone_x: settings.deadzone_x,
+            deadzone_y: settings.deadzone_y,
+            damping: settings.damping,
+            look_ahead: settings.look_ahead,
+            max_look_ahead: settings.max_look_ahead,
+            position: None,
+        }
+    }
+}
+
+impl Component for CameraFollow {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -1,10 +1,13 @@
-use crate::utils::Direction;
+use crate::utils::sprites::sprite_to_entities::bullet_sprite_and_rotation;
 use amethyst::core::ecs::{Component, DenseVecStorage};
-#[derive(Debug, Clone)]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
 pub enum CanonKind {
     Bullet,
     Smg,
     Air,
+    Missile,
     _Plasma,
 }
 
@@ -13,13 +16,14 @@ pub fn canon_to_shooting_timer(kind: &CanonKind) -> f32 {
         CanonKind::Bullet => 1.5,
         CanonKind::Smg => 0.4,
         CanonKind::Air => 0.5,
+        CanonKind::Missile => 4.,
         _ => 0.,
     }
 }
 
 #[derive(Debug)]
 pub struct Canon {
-    pub direction: Direction,
+    pub angle: f32,
     pub kind: CanonKind,
     pub bullet_x_start: f32,
     pub bullet_y_start: f32,
@@ -28,17 +32,69 @@ pub struct Canon {
 impl Component for Canon {
     type Storage = DenseVecStorage<Self>;
 }
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Turret {
+    pub kind: CanonKind,
+    pub x: f32,
+    pub y: f32,
+    pub angle: f32,
+    pub turn_rate: f32,
+    pub range: f32,
+}
+
+impl Component for Turret {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CanonObject {
+    pub kind: CanonKind,
+    pub x: f32,
+    pub y: f32,
+    pub angle: f32,
+}
+
 #[derive(Debug)]
 pub struct Bullet {
-    pub direction: Direction,
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    /// Rotation of the sprite, which the collider follows.
+    pub rotation: f32,
+    pub speed: f32,
     pub kind: CanonKind,
     pub life_duration: f32,
 }
 
+impl Bullet {
+    pub fn new(kind: &CanonKind, angle: f32, speed_scale: f32) -> Bullet {
+        let speed = canon_kind_to_bullet_speed(kind) * speed_scale;
+        Bullet {
+            velocity_x: speed * angle.cos(),
+            velocity_y: speed * angle.sin(),
+            rotation: bullet_sprite_and_rotation(kind, angle).1,
+            speed,
+            kind: kind.clone(),
+            life_duration: canon_kind_to_bullet_life_duration(kind),
+        }
+    }
+
+    pub fn angle(&self) -> f32 {
+        self.velocity_y.atan2(self.velocity_x)
+    }
+
+    pub fn steer(&mut self, angle: f32) {
+        self.velocity_x = self.speed * angle.cos();
+        self.velocity_y = self.speed * angle.sin();
+        self.rotation = bullet_sprite_and_rotation(&self.kind, angle).1;
+    }
+}
+
 pub fn canon_kind_to_bullet_life_duration(kind: &CanonKind) -> f32 {
     match kind {
         CanonKind::Bullet | CanonKind::Smg => 3.,
         CanonKind::Air => 1.2,
+        CanonKind::Missile => 8.,
         _ => 0.,
     }
 }
@@ -47,6 +103,7 @@ pub fn canon_kind_to_bullet_speed(kind: &CanonKind) -> f32 {
     match kind {
         CanonKind::Bullet | CanonKind::Smg => 180.,
         CanonKind::Air => 40.,
+        CanonKind::Missile => 110.,
         _ => 0.,
     }
 }
@@ -54,3 +111,15 @@ pub fn canon_kind_to_bullet_speed(kind: &CanonKind) -> f32 {
 impl Component for Bullet {
     type Storage = DenseVecStorage<Self>;
 }
+
+pub struct HomingMissile {
+    pub fuel: f32,
+    pub turn_rate: f32,
+}
+
+pub const MISSILE_FUEL: f32 = 3.5;
+pub const MISSILE_TURN_RATE: f32 = 2.2;
+
+impl Component for HomingMissile {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -0,0 +1,200 @@
+use amethyst::core::ecs::{Component, DenseVecStorage, Entity};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CargoState {
+    Waiting,
+    Carried,
+    Delivered,
+}
+
+/// A load to pick up and land on the delivery pad with the same `order`.
+/// Cargo has to be delivered in increasing order.
+pub struct Cargo {
+    pub order: usize,
+    pub mass: f32,
+    pub tethered: bool,
+    pub state: CargoState,
+    pub spawn_x: f32,
+    pub spawn_y: f32,
+}
+
+impl Cargo {
+    pub fn new(order: usize, mass: f32, tethered: bool, spawn_x: f32, spawn_y: f32) -> Cargo {
+        Cargo {
+            order,
+            mass,
+            tethered,
+            state: CargoState::Waiting,
+            spawn_x,
+            spawn_y,
+        }
+    }
+}
+
+impl Component for Cargo {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Verlet rope between the ship (first node) and a carried pod (last node).
+pub struct Tether {
+    pub nodes: Vec<(f32, f32)>,
+    pub previous: Vec<(f32, f32)>,
+    pub segment_length: f32,
+}
+
+impl Tether {
+    pub fn new(from: (f32, f32), to: (f32, f32), segments: usize, length: f32) -> Tether {
+        let nodes: Vec<(f32, f32)> = (0..=segments)
+            .map(|i| {
+                let t = i as f32 / segments as f32;
+                (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+            })
+            .collect();
+        Tether {
+            previous: nodes.clone(),
+            nodes,
+            segment_length: length / segments as f32,
+        }
+    }
+
+    pub fn pod(&self) -> (f32, f32) {
+        self.nodes[self.nodes.len() - 1]
+    }
+
+    /// Verlet step of every node but the first, which follows the ship.
+    /// `gravity` is in px/s².
+    pub fn integrate(&mut self, damping: f32, gravity: f32, delta: f32) {
+        for i in 1..self.nodes.len() {
+            let (x, y) = self.nodes[i];
+            let (prev_x, prev_y) = self.previous[i];
+            self.previous[i] = (x, y);
+            self.nodes[i] = (
+                x + (x - prev_x) * damping,
+                y + (y - prev_y) * damping - gravity * delta * delta,
+            );
+        }
+    }
+
+    /// One pass pulling stretched segments back to `segment_length`, the
+    /// correction shared by `inverse_mass`. A node with no inverse mass stays.
+    pub fn relax<F>(&mut self, inverse_mass: F)
+    where
+        F: Fn(usize) -> f32,
+    {
+        for i in 0..self.nodes.len() - 1 {
+            let (a, b) = (self.nodes[i], self.nodes[i + 1]);
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let distance = dx.hypot(dy);
+            // A rope only pulls, it never pushes.
+            if distance <= self.segment_length || distance == 0. {
+                continue;
+            }
+            let (wa, wb) = (inverse_mass(i), inverse_mass(i + 1));
+            if wa + wb == 0. {
+                continue;
+            }
+            let correction = (distance - self.segment_length) / distance / (wa + wb);
+            self.nodes[i] = (a.0 + dx * correction * wa, a.1 + dy * correction * wa);
+            self.nodes[i + 1] = (b.0 - dx * correction * wb, b.1 - dy * correction * wb);
+        }
+    }
+}
+
+impl Component for Tether {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Sprite drawn on one of the inner nodes of a cargo's tether.
+pub struct TetherLink {
+    pub cargo: Entity,
+    pub index: usize,
+}
+
+impl Component for TetherLink {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEGMENTS: usize = 8;
+    const LENGTH: f32 = 64.;
+    const DELTA: f32 = 1. / 60.;
+
+    fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+        (b.0 - a.0).hypot(b.1 - a.1)
+    }
+
+    /// Steps the rope like the `TetherSystem`, the ship moving along `ship`.
+    fn simulate<F>(tether: &mut Tether, steps: usize, ship: F)
+    where
+        F: Fn(usize) -> (f32, f32),
+    {
+        let last = tether.nodes.len() - 1;
+        let inverse_mass = |i: usize| {
+            if i == 0 {
+                0.
+            } else if i == last {
+                1.
+            } else {
+                20.
+            }
+        };
+        for step in 0..steps {
+            tether.nodes[0] = ship(step);
+            tether.previous[0] = ship(step);
+            tether.integrate(0.99, 90., DELTA);
+            for _ in 0..6 {
+                tether.relax(inverse_mass);
+            }
+            tether.nodes[0] = ship(step);
+        }
+    }
+
+    #[test]
+    fn new_spreads_the_nodes_evenly() {
+        let tether = Tether::new((0., 0.), (0., -64.), SEGMENTS, LENGTH);
+        assert_eq!(tether.nodes.len(), SEGMENTS + 1);
+        assert_eq!(tether.segment_length, LENGTH / SEGMENTS as f32);
+        assert_eq!(tether.pod(), (0., -64.));
+    }
+
+    #[test]
+    fn node_spacing_converges_to_the_segment_length() {
+        let mut tether = Tether::new((0., 0.), (100., -100.), SEGMENTS, LENGTH);
+        simulate(&mut tether, 600, |_| (0., 0.));
+        let segment_length = LENGTH / SEGMENTS as f32;
+        for pair in tether.nodes.windows(2) {
+            let spacing = distance(pair[0], pair[1]);
+            assert!(
+                (spacing - segment_length).abs() < 0.25,
+                "segment of {} px instead of {}",
+                spacing,
+                segment_length
+            );
+        }
+    }
+
+    #[test]
+    fn pod_stays_within_the_rope_length() {
+        let mut tether = Tether::new((0., 0.), (0., -LENGTH), SEGMENTS, LENGTH);
+        for steps in &[1, 10, 30, 120] {
+            simulate(&mut tether, *steps, |step| (step as f32 * 2., 0.));
+            let ship = tether.nodes[0];
+            assert!(
+                distance(ship, tether.pod()) <= LENGTH * 1.05,
+                "pod {} px from the ship",
+                distance(ship, tether.pod())
+            );
+        }
+    }
+
+    #[test]
+    fn slack_rope_does_not_push() {
+        let mut tether = Tether::new((0., 0.), (0., -16.), SEGMENTS, LENGTH);
+        let before = tether.nodes.clone();
+        tether.relax(|_| 1.);
+        assert_eq!(tether.nodes, before);
+    }
+}
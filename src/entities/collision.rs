@@ -1,8 +1,11 @@
-use crate::utils::{max_of_f32_vec, min_of_f32_vec, Point2D};
+use crate::utils::{distance_between_two_points, Direction, Point2D};
 use amethyst::core::ecs::{Component, DenseVecStorage};
 use geo::intersects::Intersects;
 use geo::{LineString, Polygon};
-use std::cmp::Ordering;
+use serde::Deserialize;
+
+const SWEEP_STEP: f32 = 4.;
+const SWEEP_PRECISION: f32 = 0.25;
 
 pub struct ButtonPlatform;
 
@@ -10,70 +13,248 @@ impl Component for ButtonPlatform {
     type Storage = DenseVecStorage<Self>;
 }
 
-pub struct LandingPlatform;
+/// What a landing platform does to a ship resting on it. Refuel rates are
+/// in fuel units per second.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum PadKind {
+    Plain,
+    Start,
+    Refuel { rate: f32 },
+    Repair,
+    Arrival,
+    Checkpoint,
+    Delivery { order: usize },
+}
+
+pub struct LandingPlatform {
+    pub kind: PadKind,
+}
 
 impl Component for LandingPlatform {
     type Storage = DenseVecStorage<Self>;
 }
 
+pub struct MovingPlatform {
+    pub last_x: f32,
+    pub last_y: f32,
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+}
+
+impl MovingPlatform {
+    pub fn new(x: f32, y: f32) -> MovingPlatform {
+        MovingPlatform {
+            last_x: x,
+            last_y: y,
+            velocity_x: 0.,
+            velocity_y: 0.,
+        }
+    }
+}
+
+impl Component for MovingPlatform {
+    type Storage = DenseVecStorage<Self>;
+}
+
 pub struct Arrival;
 
 impl Component for Arrival {
     type Storage = DenseVecStorage<Self>;
 }
 
+pub struct Checkpoint {
+    pub reached: bool,
+}
+
+impl Component for Checkpoint {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Pad the `VisitPads` objective asks to land on, in increasing `order`.
+pub struct Waypoint {
+    pub order: usize,
+}
+
+impl Component for Waypoint {
+    type Storage = DenseVecStorage<Self>;
+}
+
 pub struct Transparent;
 
 impl Component for Transparent {
     type Storage = DenseVecStorage<Self>;
 }
 
-#[derive(Debug)]
-pub struct Colliders {
+pub struct OneWay {
+    pub direction: Direction,
+}
+
+impl Component for OneWay {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Opaque overlay hiding a passage that is left out of the baked level
+/// image. Tiles of the same `passage` fade together.
+pub struct SecretPassage {
+    pub passage: usize,
+    pub alpha: f32,
+}
+
+impl Component for SecretPassage {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum TileBehaviour {
+    Transparent,
+    OneWay(Direction),
+    Secret,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
     pub min_x: f32,
     pub min_y: f32,
     pub max_x: f32,
     pub max_y: f32,
+}
+
+impl Aabb {
+    /// Box around nothing: it intersects nothing and leaves `union` unchanged.
+    pub const EMPTY: Aabb = Aabb {
+        min_x: f32::INFINITY,
+        min_y: f32::INFINITY,
+        max_x: f32::NEG_INFINITY,
+        max_y: f32::NEG_INFINITY,
+    };
+
+    pub fn new(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Aabb {
+        Aabb {
+            min_x: min_x.min(max_x),
+            min_y: min_y.min(max_y),
+            max_x: min_x.max(max_x),
+            max_y: min_y.max(max_y),
+        }
+    }
+
+    /// `Aabb::EMPTY` when there are no points.
+    pub fn from_points(points: &[Point2D]) -> Aabb {
+        points.iter().fold(Aabb::EMPTY, |aabb, point| Aabb {
+            min_x: aabb.min_x.min(point.x),
+            min_y: aabb.min_y.min(point.y),
+            max_x: aabb.max_x.max(point.x),
+            max_y: aabb.max_y.max(point.y),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_x > self.max_x || self.min_y > self.max_y
+    }
+
+    pub fn from_polygons(polygons: &[Polygon<f32>]) -> Aabb {
+        let points: Vec<Point2D> = polygons
+            .iter()
+            .flat_map(|polygon| polygon.exterior().0.iter())
+            .map(|coord| Point2D {
+                x: coord.x,
+                y: coord.y,
+            })
+            .collect();
+        Aabb::from_points(&points)
+    }
+
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.min_x <= other.max_x
+            && self.max_x >= other.min_x
+            && self.min_y <= other.max_y
+            && self.max_y >= other.min_y
+    }
+
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.min_x && x <= self.max_x && y >= self.min_y && y <= self.max_y
+    }
+
+    pub fn width(&self) -> f32 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.max_y - self.min_y
+    }
+
+    pub fn translate(&self, dx: f32, dy: f32) -> Aabb {
+        Aabb {
+            min_x: self.min_x + dx,
+            min_y: self.min_y + dy,
+            max_x: self.max_x + dx,
+            max_y: self.max_y + dy,
+        }
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Overlap of both boxes, `Aabb::EMPTY` when they don't meet.
+    pub fn intersection(&self, other: &Aabb) -> Aabb {
+        if !self.intersects(other) {
+            return Aabb::EMPTY;
+        }
+        Aabb {
+            min_x: self.min_x.max(other.min_x),
+            min_y: self.min_y.max(other.min_y),
+            max_x: self.max_x.min(other.max_x),
+            max_y: self.max_y.min(other.max_y),
+        }
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        (
+            (self.min_x + self.max_x) / 2.,
+            (self.min_y + self.max_y) / 2.,
+        )
+    }
+
+    pub fn normal_towards(&self, x: f32, y: f32) -> (f32, f32) {
+        let (center_x, center_y) = self.center();
+        let half_width = ((self.max_x - self.min_x) / 2.).max(1.);
+        let half_height = ((self.max_y - self.min_y) / 2.).max(1.);
+        let dx = (x - center_x) / half_width;
+        let dy = (y - center_y) / half_height;
+        if dx.abs() > dy.abs() {
+            (dx.signum(), 0.)
+        } else {
+            (0., dy.signum())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Colliders {
+    aabb: Aabb,
     colliders: Vec<Collider>,
     polygons: Vec<Polygon<f32>>,
 }
 
 impl Colliders {
     pub fn from_vec(colliders: Vec<Collider>) -> Colliders {
-        let (min_x, min_y, max_x, max_y) = {
-            (
-                colliders
-                    .iter()
-                    .map(|col| min_of_f32_vec([col.a.x, col.b.x, col.c.x, col.d.x]))
-                    .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
-                    .unwrap(),
-                colliders
-                    .iter()
-                    .map(|col| min_of_f32_vec([col.a.y, col.b.y, col.c.y, col.d.y]))
-                    .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
-                    .unwrap(),
-                colliders
-                    .iter()
-                    .map(|col| max_of_f32_vec([col.a.x, col.b.x, col.c.x, col.d.x]))
-                    .max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
-                    .unwrap(),
-                colliders
-                    .iter()
-                    .map(|col| max_of_f32_vec([col.a.y, col.b.y, col.c.y, col.d.y]))
-                    .max_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
-                    .unwrap(),
-            )
-        };
+        let aabb = colliders
+            .iter()
+            .fold(Aabb::EMPTY, |aabb, collider| aabb.union(&collider.aabb()));
         Colliders {
             polygons: colliders
                 .iter()
                 .map(|collider| collider.to_polygon())
                 .collect(),
             colliders,
-            min_x,
-            min_y,
-            max_x,
-            max_y,
+            aabb,
         }
     }
     pub fn from_points(a: Point2D, b: Point2D, c: Point2D, d: Point2D) -> Colliders {
@@ -89,13 +270,24 @@ impl Colliders {
                 vec![],
             )],
             colliders: Vec::new(),
-            min_x: min_of_f32_vec([a.x, b.x, c.x, d.x]),
-            min_y: min_of_f32_vec([a.y, b.y, c.y, d.y]),
-            max_x: max_of_f32_vec([a.x, b.x, c.x, d.x]),
-            max_y: max_of_f32_vec([a.y, b.y, c.y, d.y]),
+            aabb: Aabb::from_points(&[a, b, c, d]),
         }
     }
 
+    /// Rotates the corners around `origin`, which should be the transform
+    /// origin the sprite itself is rotated around.
+    pub fn from_rotated_collider(collider: Collider, origin: &Point2D, angle: f32) -> Colliders {
+        Colliders::from_points(
+            collider.a.rotate_around(origin, angle),
+            collider.b.rotate_around(origin, angle),
+            collider.c.rotate_around(origin, angle),
+            collider.d.rotate_around(origin, angle),
+        )
+    }
+
+    pub fn aabb(&self) -> &Aabb {
+        &self.aabb
+    }
     pub fn polygons(&self) -> &Vec<Polygon<f32>> {
         &self.polygons
     }
@@ -151,6 +343,10 @@ impl Collider {
         )
     }
 
+    pub fn aabb(&self) -> Aabb {
+        Aabb::new(self.a.x, self.a.y, self.c.x, self.c.y)
+    }
+
     pub fn top_left_point(&self) -> &Point2D {
         &self.a
     }
@@ -173,34 +369,194 @@ pub fn are_colliding(
     false
 }
 
-pub fn compute_is_eligible_for_collision(col1: &Colliders, col2: &Colliders) -> bool {
-    !(col1.min_x < col2.min_x
-        && col1.max_x < col2.min_x
-        && col1.min_x < col2.max_x
-        && col1.max_x < col2.max_x)
-        && !(col1.min_x > col2.min_x
-            && col1.max_x > col2.min_x
-            && col1.min_x > col2.max_x
-            && col1.max_x > col2.max_x)
-        && !(col1.min_y < col2.min_y
-            && col1.max_y < col2.min_y
-            && col1.min_y < col2.max_y
-            && col1.max_y < col2.max_y)
-        && !(col1.min_y > col2.min_y
-            && col1.max_y > col2.min_y
-            && col1.min_y > col2.max_y
-            && col1.max_y > col2.max_y)
-}
-
-pub fn compute_ship_is_eligible_for_collision(
-    col1: &Colliders,
-    min_x: f32,
-    max_x: f32,
-    min_y: f32,
-    max_y: f32,
-) -> bool {
-    !(col1.min_x < min_x && col1.max_x < min_x && col1.min_x < max_x && col1.max_x < max_x)
-        && !(col1.min_x > min_x && col1.max_x > min_x && col1.min_x > max_x && col1.max_x > max_x)
-        && !(col1.min_y < min_y && col1.max_y < min_y && col1.min_y < max_y && col1.max_y < max_y)
-        && !(col1.min_y > min_y && col1.max_y > min_y && col1.min_y > max_y && col1.max_y > max_y)
+/// First hit along a sweep. `x`, `y` is the last ship translation that does
+/// not overlap, `point` where the shapes meet.
+#[derive(Debug, Clone, Copy)]
+pub struct Contact<T> {
+    pub x: f32,
+    pub y: f32,
+    pub point: Point2D,
+    pub hit: T,
+}
+
+/// Moves from `from` to `to` in steps of at most `SWEEP_STEP` and returns the
+/// first position `hit_at` reports a hit for, refined down to
+/// `SWEEP_PRECISION`.
+pub fn sweep<T, F>(from: (f32, f32), to: (f32, f32), mut hit_at: F) -> Option<Contact<T>>
+where
+    F: FnMut(f32, f32) -> Option<(T, Point2D)>,
+{
+    let distance = distance_between_two_points(from.0, from.1, to.0, to.1);
+    let steps = (distance / SWEEP_STEP).ceil().max(1.) as usize;
+    let mut safe = from;
+    for step in 1..=steps {
+        let time = step as f32 / steps as f32;
+        let mut blocked = (
+            from.0 + (to.0 - from.0) * time,
+            from.1 + (to.1 - from.1) * time,
+        );
+        let mut hit = match hit_at(blocked.0, blocked.1) {
+            Some(hit) => hit,
+            None => {
+                safe = blocked;
+                continue;
+            }
+        };
+        while distance_between_two_points(safe.0, safe.1, blocked.0, blocked.1) > SWEEP_PRECISION {
+            let middle = ((safe.0 + blocked.0) / 2., (safe.1 + blocked.1) / 2.);
+            match hit_at(middle.0, middle.1) {
+                Some(closer) => {
+                    hit = closer;
+                    blocked = middle;
+                }
+                None => safe = middle,
+            }
+        }
+        let (hit, point) = hit;
+        return Some(Contact {
+            x: safe.0,
+            y: safe.1,
+            point,
+            hit,
+        });
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo::bounding_rect::BoundingRect;
+    use rand::Rng;
+    use std::f32::consts::PI;
+
+    /// Star-shaped polygon with 3 to 8 vertices around a random centre.
+    fn random_polygon() -> Polygon<f32> {
+        let mut rng = rand::thread_rng();
+        let (center_x, center_y) = (rng.gen_range(-100., 100.), rng.gen_range(-100., 100.));
+        let vertices = rng.gen_range(3, 9);
+        let mut angles: Vec<f32> = (0..vertices).map(|_| rng.gen_range(0., 2. * PI)).collect();
+        angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut points: Vec<(f32, f32)> = angles
+            .iter()
+            .map(|angle| {
+                let radius = rng.gen_range(1., 40.);
+                (
+                    center_x + radius * angle.cos(),
+                    center_y + radius * angle.sin(),
+                )
+            })
+            .collect();
+        points.push(points[0]);
+        Polygon::new(LineString::from(points), vec![])
+    }
+
+    #[test]
+    fn aabb_matches_geo_bounding_rect() {
+        for _ in 0..1000 {
+            let polygon = random_polygon();
+            let aabb = Aabb::from_polygons(&[polygon.clone()]);
+            let rect = polygon.bounding_rect().unwrap();
+            assert_eq!(aabb.min_x, rect.min().x);
+            assert_eq!(aabb.min_y, rect.min().y);
+            assert_eq!(aabb.max_x, rect.max().x);
+            assert_eq!(aabb.max_y, rect.max().y);
+        }
+    }
+
+    #[test]
+    fn aabb_intersects_like_geo_bounding_rects() {
+        for _ in 0..1000 {
+            let (a, b) = (random_polygon(), random_polygon());
+            let (aabb_a, aabb_b) = (
+                Aabb::from_polygons(&[a.clone()]),
+                Aabb::from_polygons(&[b.clone()]),
+            );
+            let (rect_a, rect_b) = (a.bounding_rect().unwrap(), b.bounding_rect().unwrap());
+            assert_eq!(
+                aabb_a.intersects(&aabb_b),
+                Polygon::from(rect_a).intersects(&Polygon::from(rect_b))
+            );
+        }
+    }
+
+    #[test]
+    fn no_aabb_overlap_means_no_polygon_overlap() {
+        for _ in 0..1000 {
+            let (a, b) = (random_polygon(), random_polygon());
+            let (aabb_a, aabb_b) = (
+                Aabb::from_polygons(&[a.clone()]),
+                Aabb::from_polygons(&[b.clone()]),
+            );
+            if !aabb_a.intersects(&aabb_b) {
+                assert!(!a.intersects(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn empty_aabb_intersects_nothing() {
+        let empty = Aabb::from_points(&[]);
+        assert!(empty.is_empty());
+        let aabb = Aabb::new(-1., -1., 1., 1.);
+        assert!(!empty.intersects(&aabb));
+        assert!(!aabb.intersects(&empty));
+        assert_eq!(empty.union(&aabb), aabb);
+    }
+
+    #[test]
+    fn aabb_contains_its_edges_only() {
+        let aabb = Aabb::new(0., 0., 10., 20.).translate(5., -5.);
+        assert!(aabb.contains(5., -5.));
+        assert!(aabb.contains(15., 15.));
+        assert!(aabb.contains(10., 0.));
+        assert!(!aabb.contains(4.9, 0.));
+        assert!(!aabb.contains(10., 15.1));
+        assert!(!Aabb::EMPTY.contains(0., 0.));
+        assert_eq!((aabb.width(), aabb.height()), (10., 20.));
+    }
+
+    /// A `TOP_WALL` tile: 32 px wide, 8 px thick, spanning y 92 to 100.
+    fn top_wall() -> Vec<Polygon<f32>> {
+        vec![Collider::new(Point2D { x: 0., y: 100. }, 32., -8.).to_polygon()]
+    }
+
+    /// A 16 px square hanging from its top left corner, like the ship.
+    fn ship_at(x: f32, y: f32) -> Vec<Polygon<f32>> {
+        vec![Collider::new(Point2D { x, y }, 16., -16.).to_polygon()]
+    }
+
+    fn hit_wall(x: f32, y: f32) -> Option<((), Point2D)> {
+        if are_colliding(&ship_at(x, y), &top_wall()) {
+            Some(((), Point2D { x, y }))
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn sweep_catches_a_motion_through_a_thin_wall() {
+        let (from, to) = ((8., 140.), (8., 60.));
+        assert!(hit_wall(from.0, from.1).is_none());
+        assert!(hit_wall(to.0, to.1).is_none());
+        assert!(sweep(from, to, hit_wall).is_some());
+    }
+
+    #[test]
+    fn sweep_stops_just_before_the_wall() {
+        let contact = sweep((8., 140.), (8., 60.), hit_wall).unwrap();
+        assert!(hit_wall(contact.x, contact.y).is_none());
+        assert_eq!(contact.x, 8.);
+        let gap = (contact.y - 16.) - 100.;
+        assert!(
+            (0. ..=SWEEP_PRECISION).contains(&gap),
+            "ship bottom {} px above the wall",
+            gap
+        );
+    }
+
+    #[test]
+    fn sweep_without_motion_finds_nothing() {
+        assert!(sweep((8., 140.), (8., 140.), hit_wall).is_none());
+    }
 }
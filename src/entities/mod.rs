@@ -2,13 +2,19 @@ use amethyst::core::ecs::{Component, DenseVecStorage};
 
 pub mod blade_saw;
 pub mod bonus;
+pub mod camera;
 pub mod canons;
+pub mod cargo;
 pub mod collision;
 pub mod doors;
 pub mod explosion;
 pub mod main_menu;
+pub mod particle;
+pub mod path;
 pub mod ship;
 pub mod sound;
+pub mod target;
+pub mod zone;
 
 pub struct TransitionFade;
 
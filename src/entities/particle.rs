@@ -0,0 +1,125 @@
+use amethyst::core::ecs::{Component, DenseVecStorage};
+
+/// Plain dot in the bullets sheet, shared by every particle-like effect.
+pub const PARTICLE_SPRITE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParticlePreset {
+    Exhaust,
+    Sparks,
+    Impact,
+    Debris,
+    Sparkles,
+}
+
+/// How particles of a preset are born and how they age. Speeds are in
+/// pixels per second, `spread` is the half angle of the emission cone.
+pub struct ParticleConfig {
+    pub burst: usize,
+    pub lifetime: (f32, f32),
+    pub speed: (f32, f32),
+    pub spread: f32,
+    pub gravity: f32,
+    pub start_color: [f32; 4],
+    pub end_color: [f32; 4],
+    pub start_scale: f32,
+    pub end_scale: f32,
+}
+
+impl ParticlePreset {
+    pub fn config(self) -> ParticleConfig {
+        match self {
+            ParticlePreset::Exhaust => ParticleConfig {
+                burst: 1,
+                lifetime: (0.2, 0.45),
+                speed: (60., 120.),
+                spread: 0.3,
+                gravity: 0.,
+                start_color: [1., 0.85, 0.4, 1.],
+                end_color: [0.6, 0.2, 0.1, 0.],
+                start_scale: 1.,
+                end_scale: 0.4,
+            },
+            ParticlePreset::Sparks => ParticleConfig {
+                burst: 10,
+                lifetime: (0.15, 0.4),
+                speed: (80., 180.),
+                spread: 1.2,
+                gravity: 200.,
+                start_color: [1., 1., 0.7, 1.],
+                end_color: [1., 0.5, 0.1, 0.],
+                start_scale: 0.6,
+                end_scale: 0.2,
+            },
+            ParticlePreset::Impact => ParticleConfig {
+                burst: 6,
+                lifetime: (0.1, 0.25),
+                speed: (40., 100.),
+                spread: 1.5,
+                gravity: 0.,
+                start_color: [1., 1., 1., 1.],
+                end_color: [1., 0.6, 0.3, 0.],
+                start_scale: 0.5,
+                end_scale: 0.1,
+            },
+            ParticlePreset::Debris => ParticleConfig {
+                burst: 30,
+                lifetime: (0.6, 1.2),
+                speed: (40., 160.),
+                spread: std::f32::consts::PI,
+                gravity: 150.,
+                start_color: [0.8, 0.8, 0.8, 1.],
+                end_color: [0.3, 0.3, 0.3, 0.],
+                start_scale: 1.2,
+                end_scale: 0.6,
+            },
+            ParticlePreset::Sparkles => ParticleConfig {
+                burst: 12,
+                lifetime: (0.3, 0.7),
+                speed: (20., 60.),
+                spread: std::f32::consts::PI,
+                gravity: -20.,
+                start_color: [1., 0.95, 0.5, 1.],
+                end_color: [1., 1., 1., 0.],
+                start_scale: 0.8,
+                end_scale: 0.1,
+            },
+        }
+    }
+}
+
+pub struct Particle {
+    pub velocity_x: f32,
+    pub velocity_y: f32,
+    pub life: f32,
+    pub max_life: f32,
+    pub preset: ParticlePreset,
+}
+
+impl Component for Particle {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Emits `rate` particles per second from the entity position, toward
+/// `direction` radians relative to the entity rotation.
+pub struct ParticleEmitter {
+    pub preset: ParticlePreset,
+    pub rate: f32,
+    pub direction: f32,
+    pub accumulator: f32,
+}
+
+impl ParticleEmitter {
+    pub fn new(preset: ParticlePreset, direction: f32) -> Self {
+        ParticleEmitter {
+            preset,
+            rate: 0.,
+            direction,
+            accumulator: 0.,
+        }
+    }
+}
+
+impl Component for ParticleEmitter {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -0,0 +1,217 @@
+use crate::entities::canons::Turret;
+use crate::entities::collision::PadKind;
+use crate::utils::distance_between_two_points;
+use amethyst::core::ecs::{Component, DenseVecStorage};
+use serde::Deserialize;
+use std::f32::consts::PI;
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum PathMode {
+    Loop,
+    PingPong,
+    OneShot,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum Easing {
+    Linear,
+    EaseInOut,
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOut => (1. - (t * PI).cos()) / 2.,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum PathKind {
+    BladeSaw,
+    /// A landing platform carrying the ship, acting as a pad of this kind.
+    Platform(PadKind),
+    /// A turret patrolling the path, shooting at the ship on its way.
+    Drone(Turret),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PathConfig {
+    pub kind: PathKind,
+    pub points: Vec<(f32, f32)>,
+    pub speeds: Vec<f32>,
+    pub mode: PathMode,
+    pub easing: Easing,
+}
+
+#[derive(Debug, Clone)]
+pub struct PathFollower {
+    points: Vec<(f32, f32)>,
+    speeds: Vec<f32>,
+    mode: PathMode,
+    easing: Easing,
+    segment: usize,
+    progress: f32,
+    forward: bool,
+    finished: bool,
+}
+
+impl PathFollower {
+    pub fn new(config: &PathConfig) -> PathFollower {
+        PathFollower {
+            points: config.points.clone(),
+            speeds: config.speeds.clone(),
+            mode: config.mode.clone(),
+            easing: config.easing.clone(),
+            segment: 0,
+            progress: 0.,
+            forward: true,
+            finished: false,
+        }
+    }
+
+    pub fn advance(&mut self, delta_time: f32) -> (f32, f32) {
+        if self.finished || self.points.len() < 2 || self.speeds.is_empty() {
+            return self.position();
+        }
+        let (from, to) = self.segment_ends();
+        let length = distance_between_two_points(from.0, from.1, to.0, to.1).max(1.);
+        self.progress += self.speeds[self.segment % self.speeds.len()] * delta_time / length;
+        while self.progress >= 1. && !self.finished {
+            self.progress -= 1.;
+            self.next_segment();
+        }
+        self.position()
+    }
+
+    pub fn position(&self) -> (f32, f32) {
+        if self.points.len() < 2 {
+            return self.points.get(0).cloned().unwrap_or((0., 0.));
+        }
+        let (from, to) = self.segment_ends();
+        let t = self.easing.apply(self.progress);
+        (from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn segment_count(&self) -> usize {
+        match self.mode {
+            PathMode::Loop => self.points.len(),
+            _ => self.points.len() - 1,
+        }
+    }
+
+    fn segment_ends(&self) -> ((f32, f32), (f32, f32)) {
+        let a = self.points[self.segment];
+        let b = self.points[(self.segment + 1) % self.points.len()];
+        if self.forward {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    fn next_segment(&mut self) {
+        let count = self.segment_count();
+        match self.mode {
+            PathMode::Loop => self.segment = (self.segment + 1) % count,
+            PathMode::PingPong => {
+                if self.forward && self.segment + 1 < count {
+                    self.segment += 1;
+                } else if !self.forward && self.segment > 0 {
+                    self.segment -= 1;
+                } else {
+                    self.forward = !self.forward;
+                }
+            }
+            PathMode::OneShot => {
+                if self.segment + 1 < count {
+                    self.segment += 1;
+                } else {
+                    self.progress = 1.;
+                    self.finished = true;
+                }
+            }
+        }
+    }
+}
+
+impl Component for PathFollower {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 0.001;
+
+    /// Square with 100 px sides, travelled at 100 px/s.
+    fn follower(mode: PathMode, easing: Easing) -> PathFollower {
+        PathFollower::new(&PathConfig {
+            kind: PathKind::BladeSaw,
+            points: vec![(0., 0.), (100., 0.), (100., 100.), (0., 100.)],
+            speeds: vec![100.],
+            mode,
+            easing,
+        })
+    }
+
+    fn assert_at(position: (f32, f32), expected: (f32, f32)) {
+        assert!(
+            (position.0 - expected.0).abs() < EPSILON && (position.1 - expected.1).abs() < EPSILON,
+            "expected {:?}, got {:?}",
+            expected,
+            position
+        );
+    }
+
+    #[test]
+    fn loop_wraps_from_the_last_point_to_the_first() {
+        let mut path = follower(PathMode::Loop, Easing::Linear);
+        assert_at(path.advance(3.5), (0., 50.));
+        assert_at(path.advance(0.5), (0., 0.));
+        assert_at(path.advance(0.25), (25., 0.));
+        assert!(!path.is_finished());
+    }
+
+    #[test]
+    fn ping_pong_turns_back_at_both_ends() {
+        let mut path = follower(PathMode::PingPong, Easing::Linear);
+        assert_at(path.advance(3.), (0., 100.));
+        assert_at(path.advance(0.5), (50., 100.));
+        assert_at(path.advance(2.5), (0., 0.));
+        assert_at(path.advance(0.5), (50., 0.));
+        assert!(!path.is_finished());
+    }
+
+    #[test]
+    fn one_shot_stops_on_the_last_point() {
+        let mut path = follower(PathMode::OneShot, Easing::Linear);
+        assert_at(path.advance(2.5), (50., 100.));
+        assert_at(path.advance(10.), (0., 100.));
+        assert!(path.is_finished());
+        assert_at(path.advance(1.), (0., 100.));
+    }
+
+    #[test]
+    fn easings_keep_their_endpoints() {
+        for easing in &[Easing::Linear, Easing::EaseInOut] {
+            assert!(easing.apply(0.).abs() < EPSILON);
+            assert!((easing.apply(1.) - 1.).abs() < EPSILON);
+            assert!((easing.apply(0.5) - 0.5).abs() < EPSILON);
+        }
+        assert!(Easing::EaseInOut.apply(0.1) < Easing::Linear.apply(0.1));
+    }
+
+    #[test]
+    fn eased_segments_still_reach_every_point() {
+        let mut path = follower(PathMode::Loop, Easing::EaseInOut);
+        assert_at(path.advance(1.), (100., 0.));
+        assert_at(path.advance(1.), (100., 100.));
+    }
+}
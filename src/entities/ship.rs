@@ -53,3 +53,21 @@ pub struct Coin {
 impl Component for Coin {
     type Storage = DenseVecStorage<Self>;
 }
+
+pub struct ShipsLeft;
+
+impl Component for ShipsLeft {
+    type Storage = DenseVecStorage<Self>;
+}
+
+pub struct StrandedPrompt;
+
+impl Component for StrandedPrompt {
+    type Storage = DenseVecStorage<Self>;
+}
+
+pub struct ObjectivesHud;
+
+impl Component for ObjectivesHud {
+    type Storage = DenseVecStorage<Self>;
+}
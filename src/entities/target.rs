@@ -0,0 +1,8 @@
+use amethyst::core::ecs::{Component, DenseVecStorage};
+
+/// Destroyed by ramming it with the ship or a towed cargo pod.
+pub struct Target;
+
+impl Component for Target {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -0,0 +1,54 @@
+use crate::entities::collision::Aabb;
+use crate::utils::Point2D;
+use amethyst::core::ecs::{Component, DenseVecStorage};
+use geo::{LineString, Polygon};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub enum ZoneKind {
+    Wind { x_force: f32, y_force: f32 },
+    GravityWell { strength: f32, radius: f32 },
+    LowGravity { gravity: f32 },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ZoneConfig {
+    pub kind: ZoneKind,
+    pub points: Vec<(f32, f32)>,
+    pub particles: bool,
+}
+
+pub struct ForceZone {
+    pub kind: ZoneKind,
+    pub polygon: Polygon<f32>,
+    pub bounds: Aabb,
+}
+
+impl ForceZone {
+    pub fn new(config: &ZoneConfig) -> ForceZone {
+        let points: Vec<Point2D> = config
+            .points
+            .iter()
+            .map(|(x, y)| Point2D { x: *x, y: *y })
+            .collect();
+        ForceZone {
+            kind: config.kind.clone(),
+            polygon: Polygon::new(LineString::from(config.points.clone()), vec![]),
+            bounds: Aabb::from_points(&points),
+        }
+    }
+}
+
+impl Component for ForceZone {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Drifts inside `bounds`, the sprite-space box of its zone.
+pub struct ZoneParticle {
+    pub zone_kind: ZoneKind,
+    pub bounds: Aabb,
+}
+
+impl Component for ZoneParticle {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -4,21 +4,46 @@ mod states;
 mod systems;
 mod utils;
 
+use crate::resources::camera_effects::{AccessibilitySettings, CameraSettings};
+use crate::resources::leaderboard::{Leaderboard, LeaderboardConfig};
+use crate::resources::main_resource::FRAMES_PER_SECOND;
 use crate::states::main_menu_state::MainMenuState;
 use crate::states::CurrentState;
 use crate::systems::blade_saw_system::BladeSawSystem;
 use crate::systems::bonus_system::BonusSystem;
 use crate::systems::bullet_system::BulletSystem;
+use crate::systems::camera_effects_system::CameraEffectsSystem;
+use crate::systems::camera_system::CameraSystem;
 use crate::systems::canon_system::CanonSystem;
+use crate::systems::cargo_system::CargoSystem;
+use crate::systems::checkpoint_system::CheckpointSystem;
 use crate::systems::collision_system::CollisionSystem;
+use crate::systems::damage_system::DamageSystem;
 use crate::systems::doors::plasma_door_system::PlasmaDoorSystem;
 use crate::systems::explosion_systems::ExplosionSystem;
-use crate::systems::landing_system::LandingSystem;
+use crate::systems::extra_ship_system::ExtraShipSystem;
+use crate::systems::force_zone_system::ForceZoneSystem;
+use crate::systems::fuel_system::FuelSystem;
+use crate::systems::homing_missile_system::HomingMissileSystem;
 use crate::systems::menu_background_system::MenuBackgroundSystem;
+use crate::systems::moving_platform_system::MovingPlatformSystem;
+use crate::systems::objectives_system::ObjectivesSystem;
+use crate::systems::pad_system::PadSystem;
+use crate::systems::particle_effects_system::ParticleEffectsSystem;
+use crate::systems::particle_system::ParticleSystem;
+use crate::systems::path_system::PathFollowerSystem;
 use crate::systems::score_system::ScoreSystem;
+use crate::systems::secret_passage_system::SecretPassageSystem;
+use crate::systems::ship_effects_system::ShipEffectsSystem;
+use crate::systems::ship_sound_system::ShipSoundSystem;
 use crate::systems::ship_systems::ShipSystem;
+use crate::systems::stats_system::StatsSystem;
+use crate::systems::target_system::TargetSystem;
+use crate::systems::tether_system::TetherSystem;
 use crate::systems::thruster_system::ThrustersSystem;
+use crate::systems::turret_system::TurretSystem;
 use crate::systems::ui_system::UISystem;
+use crate::systems::zone_particle_system::ZoneParticleSystem;
 use crate::utils::sound::Sounds;
 use amethyst::audio::{AudioBundle, DjSystem, DjSystemDesc};
 use amethyst::core::frame_limiter::FrameRateLimitStrategy;
@@ -44,6 +69,13 @@ fn main() -> amethyst::Result<()> {
     let resources = app_root.join("assets");
     let display_config = app_root.join("assets/config/display_config.ron");
     let key_bindings_path = app_root.join("assets/config/bindings.ron");
+    let leaderboard_config =
+        LeaderboardConfig::load(app_root.join("assets/config/leaderboard.ron")).unwrap_or_default();
+    let accessibility_settings =
+        AccessibilitySettings::load(app_root.join("assets/config/accessibility.ron"))
+            .unwrap_or_default();
+    let camera_settings =
+        CameraSettings::load(app_root.join("assets/config/camera.ron")).unwrap_or_default();
 
     let (r, g, b, a) = Srgba::new(31. / 255., 54. / 255., 50. / 255., 1.)
         .into_linear()
@@ -69,10 +101,39 @@ fn main() -> amethyst::Result<()> {
             "dj",
             &[],
         )
+        .with(
+            PathFollowerSystem.pausable(CurrentState::Level),
+            "path_follower_system",
+            &[],
+        )
+        .with(
+            MovingPlatformSystem.pausable(CurrentState::Level),
+            "moving_platform_system",
+            &["path_follower_system"],
+        )
+        .with(
+            ForceZoneSystem.pausable(CurrentState::Level),
+            "force_zone_system",
+            &[],
+        )
         .with(
             ShipSystem::default().pausable(CurrentState::Level),
             "ship_system",
-            &["input_system"],
+            &[
+                "input_system",
+                "moving_platform_system",
+                "force_zone_system",
+            ],
+        )
+        .with(
+            ZoneParticleSystem.pausable(CurrentState::Level),
+            "zone_particle_system",
+            &[],
+        )
+        .with(
+            SecretPassageSystem.pausable(CurrentState::Level),
+            "secret_passage_system",
+            &[],
         )
         .with(
             CollisionSystem.pausable(CurrentState::Level),
@@ -84,11 +145,6 @@ fn main() -> amethyst::Result<()> {
             "thrusters_system",
             &[],
         )
-        .with(
-            LandingSystem.pausable(CurrentState::Level),
-            "landing_system",
-            &[],
-        )
         .with(
             ExplosionSystem::new().pausable(CurrentState::Level),
             "explosion_system",
@@ -99,6 +155,16 @@ fn main() -> amethyst::Result<()> {
             "canon_system",
             &[],
         )
+        .with(
+            TurretSystem::default().pausable(CurrentState::Level),
+            "turret_system",
+            &["path_follower_system"],
+        )
+        .with(
+            HomingMissileSystem.pausable(CurrentState::Level),
+            "homing_missile_system",
+            &[],
+        )
         .with(
             BulletSystem::default().pausable(CurrentState::Level),
             "bullet_system",
@@ -115,24 +181,116 @@ fn main() -> amethyst::Result<()> {
             "menu_background_system",
             &[],
         )
-        .with(
-            ScoreSystem::default().pausable(CurrentState::Level),
-            "score_system",
-            &[],
-        )
         .with(
             BladeSawSystem.pausable(CurrentState::Level),
             "blade_saw_system",
-            &[],
+            &["path_follower_system"],
         )
         .with(
             BonusSystem::default().pausable(CurrentState::Level),
             "bonus_system",
             &[],
+        )
+        .with(
+            DamageSystem::default().pausable(CurrentState::Level),
+            "damage_system",
+            &[
+                "collision_system",
+                "bullet_system",
+                "blade_saw_system",
+                "bonus_system",
+            ],
+        )
+        .with(
+            ShipSoundSystem::default().pausable(CurrentState::Level),
+            "ship_sound_system",
+            &["damage_system"],
+        )
+        .with(
+            StatsSystem::default().pausable(CurrentState::Level),
+            "stats_system",
+            &["damage_system"],
+        )
+        .with(
+            ShipEffectsSystem::default().pausable(CurrentState::Level),
+            "ship_effects_system",
+            &["damage_system"],
+        )
+        .with(
+            CheckpointSystem::default().pausable(CurrentState::Level),
+            "checkpoint_system",
+            &["collision_system", "bonus_system"],
+        )
+        .with(
+            ExtraShipSystem::default().pausable(CurrentState::Level),
+            "extra_ship_system",
+            &["bonus_system"],
+        )
+        .with(
+            CameraEffectsSystem::default().pausable(CurrentState::Level),
+            "camera_effects_system",
+            &["damage_system"],
+        )
+        .with(
+            CameraSystem.pausable(CurrentState::Level),
+            "camera_system",
+            &["ship_system", "collision_system", "camera_effects_system"],
+        )
+        .with(
+            ParticleEffectsSystem::default().pausable(CurrentState::Level),
+            "particle_effects_system",
+            &["damage_system"],
+        )
+        .with(
+            ParticleSystem.pausable(CurrentState::Level),
+            "particle_system",
+            &[
+                "thrusters_system",
+                "bullet_system",
+                "particle_effects_system",
+            ],
+        )
+        .with(
+            PadSystem::default().pausable(CurrentState::Level),
+            "pad_system",
+            &["collision_system"],
+        )
+        .with(
+            FuelSystem::default().pausable(CurrentState::Level),
+            "fuel_system",
+            &["ship_system", "pad_system"],
+        )
+        .with(
+            CargoSystem::default().pausable(CurrentState::Level),
+            "cargo_system",
+            &["collision_system"],
+        )
+        .with(
+            TetherSystem.pausable(CurrentState::Level),
+            "tether_system",
+            &["cargo_system", "bullet_system", "blade_saw_system"],
+        )
+        .with(
+            TargetSystem.pausable(CurrentState::Level),
+            "target_system",
+            &["tether_system"],
+        )
+        .with(
+            ObjectivesSystem::default().pausable(CurrentState::Level),
+            "objectives_system",
+            &["cargo_system", "target_system", "bonus_system"],
+        )
+        .with(
+            ScoreSystem::default().pausable(CurrentState::Level),
+            "score_system",
+            &["collision_system", "objectives_system"],
         );
 
     let mut game = Application::build(resources, MainMenuState::default())?
-        .with_frame_limit(FrameRateLimitStrategy::Sleep, 60)
+        .with_frame_limit(FrameRateLimitStrategy::Sleep, FRAMES_PER_SECOND)
+        .with_resource(Leaderboard::from_config(&leaderboard_config))
+        .with_resource(accessibility_settings)
+        .with_resource(camera_settings)
         .build(game_data)?;
     game.run();
     Ok(())
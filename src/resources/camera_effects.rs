@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// Accumulated camera feedback, written by the `CameraEffectsSystem` and
+/// applied on top of the follow position by the `CameraSystem`.
+pub struct CameraEffects {
+    pub trauma: f32,
+    pub offset: (f32, f32),
+    pub speed_zoom: f32,
+    pub zoom_pulse: f32,
+    pub hit_stop: f32,
+}
+
+impl Default for CameraEffects {
+    fn default() -> Self {
+        CameraEffects {
+            trauma: 0.,
+            offset: (0., 0.),
+            speed_zoom: 1.,
+            zoom_pulse: 0.,
+            hit_stop: 0.,
+        }
+    }
+}
+
+impl CameraEffects {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.);
+    }
+
+    pub fn zoom(&self) -> f32 {
+        self.speed_zoom + self.zoom_pulse
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    pub screen_shake: bool,
+    pub camera_zoom: bool,
+    pub hit_stop: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        AccessibilitySettings {
+            screen_shake: true,
+            camera_zoom: true,
+            hit_stop: true,
+        }
+    }
+}
+
+/// Follow tuning read from `camera.ron`, see `CameraFollow`.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct CameraSettings {
+    pub deadzone_x: f32,
+    pub deadzone_y: f32,
+    pub damping: f32,
+    pub look_ahead: f32,
+    pub max_look_ahead: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        CameraSettings {
+            deadzone_x: 48.,
+            deadzone_y: 32.,
+            damping: 4.,
+            look_ahead: 40.,
+            max_look_ahead: 96.,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+pub const ARCADE_STARTING_SHIPS: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Campaign {
+    pub arcade: bool,
+    pub extra_ships: usize,
+    #[serde(default)]
+    pub total_score: u32,
+}
+
+impl Campaign {
+    pub fn new(arcade: bool) -> Campaign {
+        Campaign {
+            arcade,
+            extra_ships: if arcade { ARCADE_STARTING_SHIPS } else { 0 },
+            total_score: 0,
+        }
+    }
+
+    pub fn lose_ship(&mut self) -> bool {
+        if !self.arcade {
+            return false;
+        }
+        if self.extra_ships == 0 {
+            return true;
+        }
+        self.extra_ships -= 1;
+        false
+    }
+}
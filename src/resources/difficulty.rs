@@ -0,0 +1,133 @@
+use crate::resources::main_resource::{DamageModel, MAX_FUEL};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+    Hardcore,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Difficulty::Normal
+    }
+}
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+            Difficulty::Hardcore => "Hardcore",
+        }
+    }
+
+    pub fn next(&self) -> Difficulty {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Hardcore,
+            Difficulty::Hardcore => Difficulty::Hardcore,
+        }
+    }
+
+    pub fn previous(&self) -> Difficulty {
+        match self {
+            Difficulty::Easy => Difficulty::Easy,
+            Difficulty::Normal => Difficulty::Easy,
+            Difficulty::Hard => Difficulty::Normal,
+            Difficulty::Hardcore => Difficulty::Hard,
+        }
+    }
+
+    pub fn shooting_timer_scale(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.,
+            Difficulty::Hard => 0.8,
+            Difficulty::Hardcore => 0.65,
+        }
+    }
+
+    pub fn bullet_speed_scale(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.,
+            Difficulty::Hard => 1.2,
+            Difficulty::Hardcore => 1.35,
+        }
+    }
+
+    pub fn door_open_scale(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.,
+            Difficulty::Hard => 0.8,
+            Difficulty::Hardcore => 0.65,
+        }
+    }
+
+    pub fn starting_fuel(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 12. * 50.,
+            Difficulty::Normal => 10. * 50.,
+            Difficulty::Hard => 8. * 50.,
+            Difficulty::Hardcore => 6. * 50.,
+        }
+    }
+
+    /// The tank grows when a difficulty starts with more than `MAX_FUEL`.
+    pub fn fuel_capacity(&self) -> f32 {
+        self.starting_fuel().max(MAX_FUEL)
+    }
+
+    pub fn ship_life(&self) -> u8 {
+        match self {
+            Difficulty::Easy => 4,
+            Difficulty::Normal => 3,
+            Difficulty::Hard => 2,
+            Difficulty::Hardcore => 1,
+        }
+    }
+
+    pub fn damage_model(&self) -> DamageModel {
+        match self {
+            Difficulty::Easy => DamageModel::Graduated { restitution: 0.5 },
+            Difficulty::Normal | Difficulty::Hard | Difficulty::Hardcore => DamageModel::OneTouch,
+        }
+    }
+
+    pub fn max_landing_speed(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.8,
+            Difficulty::Normal => 1.2,
+            Difficulty::Hard => 1.,
+            Difficulty::Hardcore => 0.8,
+        }
+    }
+
+    pub fn max_landing_rotation_steps(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 12.,
+            Difficulty::Normal => 9.,
+            Difficulty::Hard => 7.,
+            Difficulty::Hardcore => 5.,
+        }
+    }
+
+    pub fn landing_overflow_tolerance(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 8.,
+            Difficulty::Normal => 5.,
+            Difficulty::Hard => 4.,
+            Difficulty::Hardcore => 2.,
+        }
+    }
+
+    pub fn restarts_campaign(&self) -> bool {
+        matches!(self, Difficulty::Hardcore)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::entities::bonus::BonusKind;
+use crate::utils::Point2D;
+use amethyst::core::ecs::Entity;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CollisionCause {
+    Wall,
+    PlasmaDoor,
+    Platform,
+    BladeSaw,
+    Bullet,
+    SelfDestruct,
+    OutOfTime,
+}
+
+#[derive(Debug, Clone)]
+pub enum ShipEvent {
+    Collided {
+        cause: CollisionCause,
+        point: Point2D,
+        normal: (f32, f32),
+        impact_speed: f32,
+    },
+    Landed {
+        platform: Entity,
+    },
+    BonusTaken {
+        kind: BonusKind,
+    },
+    Damaged {
+        cause: CollisionCause,
+        amount: u8,
+        remaining_life: u8,
+    },
+    Destroyed {
+        cause: CollisionCause,
+    },
+}
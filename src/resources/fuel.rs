@@ -0,0 +1,10 @@
+/// State of the out-of-fuel flow, kept by the `FuelSystem`.
+#[derive(Default, Debug)]
+pub struct FuelStatus {
+    pub low: bool,
+    pub gauge_visible: bool,
+    pub empty_timer: f32,
+    pub self_destruct_countdown: Option<f32>,
+    pub refuelling: bool,
+    pub fill_indicator: bool,
+}
@@ -0,0 +1,127 @@
+use crate::utils::leaderboard::file::FileBackend;
+use crate::utils::leaderboard::http::HttpBackend;
+use crate::utils::leaderboard::stub_server::StubServer;
+use crate::utils::leaderboard::{LeaderboardBackend, LeaderboardRun};
+use amethyst::utils::application_root_dir;
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+use std::thread;
+
+const RUNS_PATH: &str = "assets/.starlight_runs";
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub enum LeaderboardKind {
+    File,
+    Http(String),
+    Stub,
+}
+
+impl Default for LeaderboardKind {
+    fn default() -> Self {
+        LeaderboardKind::File
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct LeaderboardConfig {
+    pub backend: LeaderboardKind,
+}
+
+enum LeaderboardRequest {
+    Submit(LeaderboardRun),
+    Top { level: usize, count: usize },
+}
+
+/// Only `Top` requests are answered: nothing waits on a submission, so
+/// its outcome is not queued where it would pile up unread.
+pub enum LeaderboardResponse {
+    Top {
+        level: usize,
+        runs: Vec<LeaderboardRun>,
+    },
+    Failed(String),
+}
+
+/// Runs the backend on its own thread: requests are queued and answers are
+/// picked up with `poll`, so nothing here waits on the disk or the network.
+pub struct Leaderboard {
+    requests: Mutex<Sender<LeaderboardRequest>>,
+    responses: Mutex<Receiver<LeaderboardResponse>>,
+    /// Keeps the in-process server of the `Stub` backend alive for as long
+    /// as the resource: dropping it shuts the server down. Never read.
+    #[allow(dead_code)]
+    stub_server: Option<StubServer>,
+}
+
+impl Leaderboard {
+    pub fn new(mut backend: Box<dyn LeaderboardBackend>) -> Self {
+        let (request_sender, request_receiver) = channel();
+        let (response_sender, response_receiver) = channel();
+        thread::spawn(move || {
+            for request in request_receiver {
+                let response = match request {
+                    LeaderboardRequest::Submit(run) => {
+                        if let Err(e) = backend.submit(&run) {
+                            eprintln!("Could not submit the run to the leaderboard: {}", e);
+                        }
+                        continue;
+                    }
+                    LeaderboardRequest::Top { level, count } => backend
+                        .top(level, count)
+                        .map(|runs| LeaderboardResponse::Top { level, runs })
+                        .unwrap_or_else(|e| LeaderboardResponse::Failed(e.to_string())),
+                };
+                if response_sender.send(response).is_err() {
+                    break;
+                }
+            }
+        });
+        Leaderboard {
+            requests: Mutex::new(request_sender),
+            responses: Mutex::new(response_receiver),
+            stub_server: None,
+        }
+    }
+
+    pub fn from_config(config: &LeaderboardConfig) -> Self {
+        match &config.backend {
+            LeaderboardKind::File => {
+                let path = application_root_dir().unwrap().join(RUNS_PATH);
+                Leaderboard::new(Box::new(FileBackend::new(path)))
+            }
+            LeaderboardKind::Http(address) => {
+                Leaderboard::new(Box::new(HttpBackend::new(address.clone())))
+            }
+            LeaderboardKind::Stub => match StubServer::start() {
+                Ok(server) => {
+                    let mut leaderboard =
+                        Leaderboard::new(Box::new(HttpBackend::new(server.address())));
+                    leaderboard.stub_server = Some(server);
+                    leaderboard
+                }
+                Err(e) => {
+                    eprintln!("Could not start the leaderboard stub server: {}", e);
+                    Leaderboard::from_config(&LeaderboardConfig::default())
+                }
+            },
+        }
+    }
+
+    pub fn submit(&self, run: LeaderboardRun) {
+        self.send(LeaderboardRequest::Submit(run));
+    }
+
+    pub fn request_top(&self, level: usize, count: usize) {
+        self.send(LeaderboardRequest::Top { level, count });
+    }
+
+    pub fn poll(&self) -> Vec<LeaderboardResponse> {
+        let responses = self.responses.lock().unwrap();
+        responses.try_iter().collect()
+    }
+
+    fn send(&self, request: LeaderboardRequest) {
+        let _res = self.requests.lock().unwrap().send(request);
+    }
+}
@@ -1,8 +1,10 @@
 use crate::entities::collision::{Collider, Colliders};
+use crate::resources::difficulty::Difficulty;
 use crate::utils::level_reader::LevelConfig;
 use crate::utils::Point2D;
 use amethyst::assets::Handle;
 use amethyst::core::alga::linear::Similarity;
+use amethyst::core::ecs::Entity;
 use amethyst::core::math::UnitQuaternion;
 use amethyst::renderer::SpriteSheet;
 use core::cmp;
@@ -10,6 +12,12 @@ use geo::rotate::RotatePoint;
 use geo::{LineString, Point, Polygon};
 use rand::Rng;
 
+pub const MAX_FUEL: f32 = 500.;
+/// Ship forces are in px per frame at this frame rate.
+pub const FRAMES_PER_SECOND: u32 = 60;
+/// Downward force the ship gains every second, in px per frame.
+pub const GRAVITY: f32 = 1.5;
+
 pub struct MainResource {
     pub x_force: f32,
     pub y_force: f32,
@@ -30,6 +38,34 @@ pub struct MainResource {
     pub bullet_hit_timer: f32,
     pub collected_coin: usize,
     pub should_go_to_next_level: bool,
+    pub landed_platform: Option<Entity>,
+    pub platform_velocity: (f32, f32),
+    pub gravity_scale: f32,
+    pub last_motion: (f32, f32),
+    pub damage_model: DamageModel,
+    pub hull_wear: f32,
+    pub difficulty: Difficulty,
+    pub campaign_failed: bool,
+    pub checkpoint: Option<CheckpointSnapshot>,
+    pub game_over: bool,
+    pub all_coins_awarded: bool,
+    pub carried_cargo: Option<Entity>,
+    pub cargo_mass: f32,
+    pub delivered_cargo: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckpointSnapshot {
+    pub x: f32,
+    pub y: f32,
+    pub collected_coin: usize,
+    pub taken_bonuses: Vec<Entity>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DamageModel {
+    OneTouch,
+    Graduated { restitution: f32 },
 }
 
 pub struct MainSprites {
@@ -44,6 +80,7 @@ impl MainResource {
         gravity: f32,
         current_level_config: Option<LevelConfig>,
         lvl_nb: usize,
+        difficulty: Difficulty,
     ) -> MainResource {
         MainResource {
             x_force,
@@ -57,19 +94,33 @@ impl MainResource {
             should_be_reset: false,
             current_level_config,
             sprites: None,
-            ship_life: 3,
+            ship_life: difficulty.ship_life(),
             current_level: lvl_nb,
-            ship_fuel: 10. * 50.,
+            ship_fuel: difficulty.starting_fuel(),
             should_reset_plasma_timers: true,
             should_reset_bonuses: true,
             bullet_hit_timer: 0.,
             collected_coin: 0,
             should_go_to_next_level: false,
+            landed_platform: None,
+            platform_velocity: (0., 0.),
+            gravity_scale: 1.,
+            last_motion: (0., 0.),
+            damage_model: difficulty.damage_model(),
+            hull_wear: 0.,
+            difficulty,
+            campaign_failed: false,
+            checkpoint: None,
+            game_over: false,
+            all_coins_awarded: false,
+            carried_cargo: None,
+            cargo_mass: 0.,
+            delivered_cargo: 0,
         }
     }
 
     pub fn bonus_heal(&mut self) {
-        if self.ship_life < 3 {
+        if self.ship_life < self.difficulty.ship_life() {
             self.ship_life += 1;
         }
     }
@@ -79,8 +130,8 @@ impl MainResource {
 
     pub fn bonus_fuel(&mut self) {
         self.ship_fuel += 4. * 50.;
-        if self.ship_fuel > 10. * 50. {
-            self.ship_fuel = 10. * 50.;
+        if self.ship_fuel > self.difficulty.fuel_capacity() {
+            self.ship_fuel = self.difficulty.fuel_capacity();
         }
     }
 
@@ -88,8 +139,12 @@ impl MainResource {
         &(self.current_level_config.as_ref().unwrap())
     }
 
-    pub fn new_from_level(config: Option<LevelConfig>, lvl_nb: usize) -> MainResource {
-        MainResource::new(0., 0., 1.0, config, lvl_nb)
+    pub fn new_from_level(
+        config: Option<LevelConfig>,
+        lvl_nb: usize,
+        difficulty: Difficulty,
+    ) -> MainResource {
+        MainResource::new(0., 0., 1.0, config, lvl_nb, difficulty)
     }
 
     pub fn reset(&mut self) {
@@ -100,25 +155,52 @@ impl MainResource {
         self.current_rotation_angle = 0.;
         self.is_exploding = false;
         self.should_be_reset = false;
-        self.ship_life = 3;
-        self.ship_fuel = 10. * 50.;
+        self.ship_life = self.difficulty.ship_life();
+        self.ship_fuel = self.difficulty.starting_fuel();
         self.bullet_hit_timer = 0.;
         self.should_reset_bonuses = true;
-        self.collected_coin = 0;
+        self.collected_coin = self
+            .checkpoint
+            .as_ref()
+            .map(|checkpoint| checkpoint.collected_coin)
+            .unwrap_or(0);
+        self.landed_platform = None;
+        self.platform_velocity = (0., 0.);
+        self.last_motion = (0., 0.);
+        self.hull_wear = 0.;
+        self.carried_cargo = None;
+        self.cargo_mass = 0.;
+    }
+
+    pub fn land_on(&mut self, platform: Entity) {
+        self.is_landed = true;
+        self.landed_platform = Some(platform);
+        self.y_force = 0.;
+        self.x_force = 0.;
     }
 
     pub fn power(&mut self, delta_time: f32, rotation: &UnitQuaternion<f32>) {
+        if self.is_landed {
+            self.x_force += self.platform_velocity.0;
+            self.y_force += self.platform_velocity.1;
+            self.platform_velocity = (0., 0.);
+            self.landed_platform = None;
+        }
         self.is_landed = false;
-        self.y_force += delta_time * calculate_y_force(rotation.rotation().quaternion().k);
-        self.x_force += delta_time * calculate_x_force(rotation.rotation().quaternion().k);
+        // Carried cargo makes the ship sluggish to accelerate.
+        let inertia = 1. + self.cargo_mass;
+        self.y_force +=
+            delta_time * calculate_y_force(rotation.rotation().quaternion().k) / inertia;
+        self.x_force +=
+            delta_time * calculate_x_force(rotation.rotation().quaternion().k) / inertia;
         self.ship_fuel -= cmp::max(self.power, 30) as f32 * delta_time;
         self.power += 1;
     }
 
-    pub fn fuel_up(&mut self, delta_time: f32) {
-        self.ship_fuel += 100. * delta_time;
-        if self.ship_fuel > 500. {
-            self.ship_fuel = 500.;
+    pub fn fuel_up(&mut self, rate: f32, delta_time: f32) {
+        self.ship_fuel += rate * delta_time;
+        if self.ship_fuel > self.difficulty.fuel_capacity() {
+            self.ship_fuel = self.difficulty.fuel_capacity();
         }
     }
 
@@ -126,7 +208,7 @@ impl MainResource {
         if self.is_landed {
             return;
         }
-        self.y_force -= 1.5 * delta_time;
+        self.y_force -= GRAVITY * self.gravity_scale * delta_time;
         if self.x_force > 0. {
             self.x_force -= 0.2 * delta_time;
             if self.x_force < 0. {
@@ -142,11 +224,24 @@ impl MainResource {
         self.power = 0;
     }
 
-    pub fn bullet_hit(&mut self) {
-        if self.ship_life > 0 {
-            self.ship_life -= 1;
+    pub fn take_damage(&mut self, amount: u8) {
+        self.ship_life = self.ship_life.saturating_sub(amount);
+    }
+
+    pub fn wear_hull(&mut self, wear: f32) -> u8 {
+        self.hull_wear += wear;
+        let lost = self.hull_wear.floor().min(self.ship_life as f32) as u8;
+        self.hull_wear -= lost as f32;
+        self.take_damage(lost);
+        lost
+    }
+
+    pub fn bounce(&mut self, normal: (f32, f32), restitution: f32) {
+        let normal_speed = self.x_force * normal.0 + self.y_force * normal.1;
+        if normal_speed < 0. {
+            self.x_force -= (1. + restitution) * normal_speed * normal.0;
+            self.y_force -= (1. + restitution) * normal_speed * normal.1;
         }
-        self.bullet_hit_timer = 0.3;
     }
 
     pub fn get_colliders_for_collision(&self, x: f32, y: f32) -> Vec<Polygon<f32>> {
@@ -250,7 +345,7 @@ impl MainResource {
 
 impl Default for MainResource {
     fn default() -> Self {
-        MainResource::new(0., 0., 0., None, 0)
+        MainResource::new(0., 0., 0., None, 0, Difficulty::default())
     }
 }
 
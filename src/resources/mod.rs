@@ -1 +1,12 @@
+pub mod camera_effects;
+pub mod campaign;
+pub mod difficulty;
+pub mod events;
+pub mod fuel;
+pub mod leaderboard;
 pub mod main_resource;
+pub mod objectives;
+pub mod particles;
+pub mod score;
+pub mod spatial_index;
+pub mod stats;
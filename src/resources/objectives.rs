@@ -0,0 +1,199 @@
+use crate::utils::level_reader::LevelConfig;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// A win condition of a level. Levels list them in their `objectives`
+/// property, separated by `;`, e.g. `coins 5; pads; survive 30; time 90`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum Objective {
+    /// `coins` for every coin of the level, `coins N` for N of them.
+    CollectCoins(Option<usize>),
+    /// `cargo`: deliver every cargo pod.
+    DeliverCargo,
+    /// `pads`: land on the pads with a `visit` property, in order.
+    VisitPads,
+    /// `targets`: destroy every target.
+    DestroyTargets,
+    /// `survive X`: stay alive X seconds in a row.
+    Survive(f32),
+    /// `landing X`: touch down on the arrival slower than X, in HUD speed units.
+    SoftLanding(f32),
+    /// `time X`: reach the arrival within X seconds of the last spawn.
+    FinishWithin(f32),
+}
+
+impl Objective {
+    /// What a level asks for when it doesn't list its objectives.
+    pub fn defaults(config: &LevelConfig) -> Vec<Objective> {
+        let mut objectives = vec![Objective::CollectCoins(None)];
+        if !config.cargos.is_empty() {
+            objectives.push(Objective::DeliverCargo);
+        }
+        objectives
+    }
+
+    pub fn describe(&self, config: &LevelConfig) -> String {
+        match self {
+            Objective::CollectCoins(None) => format!("Collect all {} coins", config.coin_nb),
+            Objective::CollectCoins(Some(count)) => format!("Collect {} coins", count),
+            Objective::DeliverCargo => format!("Deliver {} cargo pods", config.cargos.len()),
+            Objective::VisitPads => format!("Visit {} pads in order", config.waypoint_count()),
+            Objective::DestroyTargets => format!("Destroy {} targets", config.targets.len()),
+            Objective::Survive(seconds) => format!("Survive {} seconds", seconds),
+            Objective::SoftLanding(speed) => format!("Land slower than {}", speed),
+            Objective::FinishWithin(seconds) => format!("Finish within {} seconds", seconds),
+        }
+    }
+}
+
+impl FromStr for Objective {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let kind = words.next().unwrap_or("");
+        let value = words.next();
+        let number = || -> Result<f32, String> {
+            value
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| format!("Objective '{}' needs a number", s.trim()))
+        };
+        match kind {
+            "coins" => match value {
+                Some(count) => count
+                    .parse()
+                    .map(|count| Objective::CollectCoins(Some(count)))
+                    .map_err(|_| format!("Objective '{}' needs a coin count", s.trim())),
+                None => Ok(Objective::CollectCoins(None)),
+            },
+            "cargo" => Ok(Objective::DeliverCargo),
+            "pads" => Ok(Objective::VisitPads),
+            "targets" => Ok(Objective::DestroyTargets),
+            "survive" => Ok(Objective::Survive(number()?)),
+            "landing" => Ok(Objective::SoftLanding(number()?)),
+            "time" => Ok(Objective::FinishWithin(number()?)),
+            _ => Err(format!("Unknown objective '{}'", s.trim())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ObjectiveStatus {
+    Pending,
+    Done,
+    Failed,
+}
+
+/// Progress on the level's objectives, kept by the `ObjectivesSystem`.
+#[derive(Default, Debug)]
+pub struct Objectives {
+    pub entries: Vec<(Objective, ObjectiveStatus)>,
+    pub next_waypoint: usize,
+    pub targets_destroyed: usize,
+    pub life_time: f32,
+    /// Speed of the landing on the arrival, while the ship stays on it.
+    pub last_landing_speed: Option<f32>,
+}
+
+impl Objectives {
+    pub fn new(config: &LevelConfig) -> Objectives {
+        Objectives {
+            entries: config
+                .objectives
+                .iter()
+                .map(|objective| (*objective, ObjectiveStatus::Pending))
+                .collect(),
+            ..Objectives::default()
+        }
+    }
+
+    /// Whether landing on the arrival now wins the level. Time limits only
+    /// have to not be failed yet.
+    pub fn completed(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|(objective, status)| match objective {
+                Objective::FinishWithin(_) => *status != ObjectiveStatus::Failed,
+                _ => *status == ObjectiveStatus::Done,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn objectives(entries: Vec<(Objective, ObjectiveStatus)>) -> Objectives {
+        Objectives {
+            entries,
+            ..Objectives::default()
+        }
+    }
+
+    fn parse(objective: &str) -> Result<Objective, String> {
+        objective.parse()
+    }
+
+    #[test]
+    fn parses_every_objective() {
+        assert_eq!(parse("coins"), Ok(Objective::CollectCoins(None)));
+        assert_eq!(parse(" coins 5 "), Ok(Objective::CollectCoins(Some(5))));
+        assert_eq!(parse("cargo"), Ok(Objective::DeliverCargo));
+        assert_eq!(parse("pads"), Ok(Objective::VisitPads));
+        assert_eq!(parse("targets"), Ok(Objective::DestroyTargets));
+        assert_eq!(parse("survive 30"), Ok(Objective::Survive(30.)));
+        assert_eq!(parse("landing 1.5"), Ok(Objective::SoftLanding(1.5)));
+        assert_eq!(parse("time 90"), Ok(Objective::FinishWithin(90.)));
+    }
+
+    #[test]
+    fn rejects_malformed_numbers() {
+        for objective in &[
+            "coins abc",
+            "coins 5x",
+            "coins -1",
+            "survive thirty",
+            "time",
+        ] {
+            assert!(
+                parse(objective).is_err(),
+                "'{}' should not parse",
+                objective
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_objectives() {
+        assert!(parse("escort 3").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn completed_needs_every_objective_done() {
+        assert!(!objectives(vec![
+            (Objective::CollectCoins(None), ObjectiveStatus::Done),
+            (Objective::DeliverCargo, ObjectiveStatus::Pending),
+        ])
+        .completed());
+        assert!(objectives(vec![
+            (Objective::CollectCoins(None), ObjectiveStatus::Done),
+            (Objective::DeliverCargo, ObjectiveStatus::Done),
+        ])
+        .completed());
+    }
+
+    #[test]
+    fn time_limits_only_have_to_not_be_failed() {
+        assert!(objectives(vec![(
+            Objective::FinishWithin(90.),
+            ObjectiveStatus::Pending
+        )])
+        .completed());
+        assert!(!objectives(vec![(
+            Objective::FinishWithin(90.),
+            ObjectiveStatus::Failed
+        )])
+        .completed());
+    }
+}
@@ -0,0 +1,29 @@
+use crate::entities::particle::ParticlePreset;
+
+pub struct ParticleBurst {
+    pub preset: ParticlePreset,
+    pub x: f32,
+    pub y: f32,
+    pub direction: f32,
+}
+
+/// Bursts requested during the frame, spawned by the `ParticleSystem`.
+#[derive(Default)]
+pub struct ParticleBursts {
+    bursts: Vec<ParticleBurst>,
+}
+
+impl ParticleBursts {
+    pub fn push(&mut self, preset: ParticlePreset, x: f32, y: f32, direction: f32) {
+        self.bursts.push(ParticleBurst {
+            preset,
+            x,
+            y,
+            direction,
+        });
+    }
+
+    pub fn drain(&mut self) -> Vec<ParticleBurst> {
+        self.bursts.drain(..).collect()
+    }
+}
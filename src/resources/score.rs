@@ -0,0 +1,105 @@
+use crate::resources::main_resource::MainResource;
+use crate::resources::stats::ShipStats;
+
+pub const COMBO_WINDOW: f32 = 2.;
+const COIN_POINTS: u32 = 100;
+const COMBO_POINTS: u32 = 50;
+const HULL_POINTS: u32 = 200;
+const TIME_BONUS_MAX: f32 = 3000.;
+const TIME_BONUS_PER_SECOND: f32 = 25.;
+const NO_DEATH_BONUS: u32 = 1000;
+const PRECISION_BONUS_MAX: f32 = 500.;
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+#[derive(Default, Debug)]
+pub struct ScoreTracker {
+    pub elapsed: f32,
+    pub combo: usize,
+    pub combo_timer: f32,
+    pub combo_points: u32,
+    pub replay_hash: u64,
+    pub breakdown: Option<ScoreBreakdown>,
+}
+
+impl ScoreTracker {
+    pub fn coin_taken(&mut self) {
+        if self.combo_timer > 0. {
+            self.combo += 1;
+            self.combo_points += COMBO_POINTS * self.combo as u32;
+        } else {
+            self.combo = 0;
+        }
+        self.combo_timer = COMBO_WINDOW;
+    }
+
+    pub fn tick(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+        self.combo_timer -= delta_time;
+    }
+
+    /// Folds the ship state of the frame into an FNV-1a hash of the run.
+    pub fn record_frame(&mut self, main_resource: &MainResource) {
+        if self.replay_hash == 0 {
+            self.replay_hash = FNV_OFFSET;
+        }
+        let state = [
+            main_resource.x_force.to_bits(),
+            main_resource.y_force.to_bits(),
+            main_resource.current_rotation_angle.to_bits(),
+            main_resource.power as u32,
+        ];
+        for byte in state.iter().flat_map(|value| value.to_le_bytes().to_vec()) {
+            self.replay_hash ^= byte as u64;
+            self.replay_hash = self.replay_hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ScoreBreakdown {
+    pub coins: u32,
+    pub combo: u32,
+    pub fuel: u32,
+    pub hull: u32,
+    pub time: u32,
+    pub no_death: u32,
+    pub precision: u32,
+}
+
+impl ScoreBreakdown {
+    /// `landing_offset` is the distance between the ship and the arrival
+    /// platform centres, relative to half the platform width.
+    pub fn compute(
+        main_resource: &MainResource,
+        stats: &ShipStats,
+        tracker: &ScoreTracker,
+        landing_offset: f32,
+    ) -> ScoreBreakdown {
+        ScoreBreakdown {
+            coins: main_resource.collected_coin as u32 * COIN_POINTS,
+            combo: tracker.combo_points,
+            fuel: main_resource.ship_fuel.max(0.) as u32,
+            hull: main_resource.ship_life as u32 * HULL_POINTS,
+            time: (TIME_BONUS_MAX - tracker.elapsed * TIME_BONUS_PER_SECOND).max(0.) as u32,
+            no_death: if stats.deaths == 0 { NO_DEATH_BONUS } else { 0 },
+            precision: (PRECISION_BONUS_MAX * (1. - landing_offset.min(1.))) as u32,
+        }
+    }
+
+    pub fn lines(&self) -> Vec<(&'static str, u32)> {
+        vec![
+            ("Coins", self.coins),
+            ("Combos", self.combo),
+            ("Fuel", self.fuel),
+            ("Hull", self.hull),
+            ("Time", self.time),
+            ("No death", self.no_death),
+            ("Precision", self.precision),
+        ]
+    }
+
+    pub fn total(&self) -> u32 {
+        self.lines().iter().map(|(_, points)| points).sum()
+    }
+}
@@ -0,0 +1,171 @@
+use crate::entities::collision::{Aabb, Colliders};
+use crate::utils::sprites::TILE_SIZE;
+use amethyst::core::ecs::Entity;
+use std::collections::{HashMap, HashSet};
+
+const CELL_SIZE: f32 = TILE_SIZE;
+
+#[derive(Default)]
+pub struct SpatialIndex {
+    cells: HashMap<(i32, i32), Vec<Entity>>,
+    entity_cells: HashMap<Entity, Vec<(i32, i32)>>,
+}
+
+impl SpatialIndex {
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entity_cells.clear();
+    }
+
+    pub fn insert(&mut self, entity: Entity, colliders: &Colliders) {
+        self.insert_area(entity, colliders.aabb());
+    }
+
+    /// Indexes an entity without `Colliders`, such as a bonus or a blade saw.
+    pub fn insert_area(&mut self, entity: Entity, aabb: &Aabb) {
+        let cells = cells_for_area(aabb);
+        for cell in cells.iter() {
+            self.cells
+                .entry(*cell)
+                .or_insert_with(Vec::new)
+                .push(entity);
+        }
+        self.entity_cells.insert(entity, cells);
+    }
+
+    pub fn remove(&mut self, entity: Entity) {
+        if let Some(cells) = self.entity_cells.remove(&entity) {
+            for cell in cells.iter() {
+                if let Some(entities) = self.cells.get_mut(cell) {
+                    entities.retain(|e| *e != entity);
+                }
+            }
+        }
+    }
+
+    pub fn update(&mut self, entity: Entity, colliders: &Colliders) {
+        self.update_area(entity, colliders.aabb());
+    }
+
+    pub fn update_area(&mut self, entity: Entity, aabb: &Aabb) {
+        self.remove(entity);
+        self.insert_area(entity, aabb);
+    }
+
+    pub fn query(&self, aabb: &Aabb) -> Vec<Entity> {
+        let mut found: HashSet<Entity> = HashSet::new();
+        for cell in cells_for_area(aabb) {
+            if let Some(entities) = self.cells.get(&cell) {
+                found.extend(entities.iter());
+            }
+        }
+        found.into_iter().collect()
+    }
+
+    pub fn query_colliders(&self, colliders: &Colliders) -> Vec<Entity> {
+        self.query(colliders.aabb())
+    }
+}
+
+fn cells_for_area(aabb: &Aabb) -> Vec<(i32, i32)> {
+    if aabb.is_empty() {
+        return Vec::new();
+    }
+    let (start_x, end_x) = (
+        (aabb.min_x / CELL_SIZE).floor() as i32,
+        (aabb.max_x / CELL_SIZE).floor() as i32,
+    );
+    let (start_y, end_y) = (
+        (aabb.min_y / CELL_SIZE).floor() as i32,
+        (aabb.max_y / CELL_SIZE).floor() as i32,
+    );
+    let mut cells = Vec::new();
+    for x in start_x..=end_x {
+        for y in start_y..=end_y {
+            cells.push((x, y));
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::level_reader::read_level;
+    use crate::utils::sprites::sprite_to_entities::sprite_to_colliders;
+    use amethyst::core::ecs::{Builder, World, WorldExt};
+    use rand::Rng;
+
+    fn level_colliders(level_nb: usize) -> (SpatialIndex, Vec<(Entity, Colliders)>, Aabb) {
+        let level = read_level(level_nb);
+        let mut world = World::new();
+        let mut index = SpatialIndex::default();
+        let mut all = Vec::new();
+        for (point, sprite) in level.tiles.iter() {
+            let x = point.x as f32 * TILE_SIZE;
+            let y = point.y as f32 * TILE_SIZE;
+            if let Some(colliders) = sprite_to_colliders(*sprite, x, y) {
+                let entity = world.create_entity().build();
+                index.insert(entity, &colliders);
+                all.push((entity, colliders));
+            }
+        }
+        let bounds = Aabb::new(
+            0.,
+            0.,
+            level.width as f32 * TILE_SIZE,
+            level.height as f32 * TILE_SIZE,
+        );
+        (index, all, bounds)
+    }
+
+    fn random_ship_box(bounds: &Aabb) -> Aabb {
+        let mut rng = rand::thread_rng();
+        let x = rng.gen_range(bounds.min_x, bounds.max_x);
+        let y = rng.gen_range(bounds.min_y, bounds.max_y);
+        Aabb::new(x, y, x + TILE_SIZE, y - TILE_SIZE)
+    }
+
+    fn scan(all: &[(Entity, Colliders)], area: &Aabb) -> HashSet<Entity> {
+        all.iter()
+            .filter(|(_, colliders)| colliders.aabb().intersects(area))
+            .map(|(entity, _)| *entity)
+            .collect()
+    }
+
+    fn indexed(
+        index: &SpatialIndex,
+        by_entity: &HashMap<Entity, &Colliders>,
+        area: &Aabb,
+    ) -> HashSet<Entity> {
+        index
+            .query(area)
+            .into_iter()
+            .filter(|entity| by_entity[entity].aabb().intersects(area))
+            .collect()
+    }
+
+    fn by_entity(all: &[(Entity, Colliders)]) -> HashMap<Entity, &Colliders> {
+        all.iter()
+            .map(|(entity, colliders)| (*entity, colliders))
+            .collect()
+    }
+
+    #[test]
+    fn query_finds_the_same_colliders_as_a_full_scan() {
+        let (index, all, bounds) = level_colliders(10);
+        let by_entity = by_entity(&all);
+        for _ in 0..500 {
+            let area = random_ship_box(&bounds);
+            assert_eq!(indexed(&index, &by_entity, &area), scan(&all, &area));
+        }
+    }
+
+    #[test]
+    fn removed_entities_are_not_returned() {
+        let (mut index, all, _) = level_colliders(10);
+        let (entity, colliders) = &all[0];
+        index.remove(*entity);
+        assert!(!index.query(colliders.aabb()).contains(entity));
+    }
+}
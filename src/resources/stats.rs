@@ -0,0 +1,8 @@
+#[derive(Default, Debug)]
+pub struct ShipStats {
+    pub collisions: usize,
+    pub landings: usize,
+    pub bonuses_taken: usize,
+    pub damage_taken: usize,
+    pub deaths: usize,
+}
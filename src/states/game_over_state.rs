@@ -0,0 +1,91 @@
+use crate::states::name_entry_state::{enter_high_scores, AfterEntry};
+use crate::states::CurrentState;
+use crate::utils::high_scores::HighScoreTable;
+use crate::utils::sprites::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use amethyst::assets::Loader;
+use amethyst::core::ecs::{Builder, World, WorldExt};
+use amethyst::core::Transform;
+use amethyst::renderer::Camera;
+use amethyst::ui::{Anchor, LineMode, TtfFormat, UiText, UiTransform};
+use amethyst::{GameData, SimpleState, SimpleTrans, StateData, Trans};
+
+pub struct GameOverState {
+    frame_counter: f32,
+    campaign_score: u32,
+}
+
+impl GameOverState {
+    pub fn new(campaign_score: u32) -> Self {
+        GameOverState {
+            frame_counter: 180.,
+            campaign_score,
+        }
+    }
+}
+
+impl SimpleState for GameOverState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        *data.world.write_resource::<CurrentState>() = CurrentState::GameOver;
+        let world = data.world;
+        initialise_camera(world);
+        initialise_texts(world);
+    }
+
+    fn fixed_update(&mut self, _data: StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        if self.frame_counter <= 0. {
+            return enter_high_scores(
+                vec![(HighScoreTable::Campaign, self.campaign_score)],
+                AfterEntry::MainMenu,
+            );
+        }
+        self.frame_counter -= 1.;
+        Trans::None
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.delete_all();
+        *data.world.write_resource::<CurrentState>() = CurrentState::MainMenu;
+    }
+}
+
+fn initialise_camera(world: &mut World) {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(0., 0., 1.1);
+    world
+        .create_entity()
+        .with(Camera::standard_2d(SCREEN_WIDTH, SCREEN_HEIGHT))
+        .with(transform)
+        .build();
+}
+
+fn initialise_texts(world: &mut World) {
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+
+    let game_over_transform = UiTransform::new(
+        "game_over".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        0.,
+        1.,
+        704.,
+        400.,
+    );
+    world
+        .create_entity()
+        .with(game_over_transform)
+        .with(UiText::new(
+            font,
+            "Game Over".to_string(),
+            [1., 1., 1., 1.],
+            80.,
+            LineMode::Wrap,
+            Anchor::Middle,
+        ))
+        .build();
+}
@@ -0,0 +1,179 @@
+use crate::entities::sound::MenuSound;
+use crate::resources::leaderboard::{Leaderboard, LeaderboardResponse};
+use crate::states::level_state::MAX_LVL;
+use crate::states::main_menu_state::MainMenuState;
+use crate::states::CurrentState;
+use crate::utils::high_scores::{read_high_scores, HighScoreTable, HighScores, HIGH_SCORES_LEN};
+use crate::utils::sprites::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use amethyst::assets::{Handle, Loader};
+use amethyst::core::ecs::{Builder, Entity, World, WorldExt};
+use amethyst::core::Transform;
+use amethyst::input::{is_key_down, VirtualKeyCode};
+use amethyst::renderer::Camera;
+use amethyst::ui::{Anchor, FontAsset, LineMode, TtfFormat, UiText, UiTransform};
+use amethyst::{GameData, SimpleState, SimpleTrans, StateData, StateEvent, Trans};
+
+/// Browses the campaign table, then every level table, with Left and Right.
+#[derive(Default)]
+pub struct HighScoresState {
+    high_scores: HighScores,
+    table_index: usize,
+    title_text: Option<Entity>,
+    row_texts: Vec<Entity>,
+    online_text: Option<Entity>,
+}
+
+impl HighScoresState {
+    fn table(&self) -> HighScoreTable {
+        match self.table_index {
+            0 => HighScoreTable::Campaign,
+            index => HighScoreTable::Level(index - 1),
+        }
+    }
+
+    fn refresh(&self, world: &mut World) {
+        let table = self.table();
+        let online_label = match table {
+            HighScoreTable::Level(level_nb) => {
+                world
+                    .read_resource::<Leaderboard>()
+                    .request_top(level_nb, 1);
+                "Online best: ...".to_string()
+            }
+            HighScoreTable::Campaign => String::new(),
+        };
+        self.set_online_label(world, online_label);
+        let entries = self.high_scores.entries(table);
+        let mut ui_texts = world.write_storage::<UiText>();
+        if let Some(entity) = self.title_text {
+            if let Some(text) = ui_texts.get_mut(entity) {
+                text.text = format!("< {} >", table.title());
+            }
+        }
+        for (rank, entity) in self.row_texts.iter().enumerate() {
+            if let Some(text) = ui_texts.get_mut(*entity) {
+                text.text = match entries.get(rank) {
+                    Some(entry) => format!("{:>2}. {:<3} {:>7}", rank + 1, entry.name, entry.score),
+                    None => format!("{:>2}. --- {:>7}", rank + 1, "-"),
+                };
+            }
+        }
+    }
+
+    fn set_online_label(&self, world: &mut World, label: String) {
+        if let Some(entity) = self.online_text {
+            if let Some(text) = world.write_storage::<UiText>().get_mut(entity) {
+                text.text = label;
+            }
+        }
+    }
+}
+
+impl SimpleState for HighScoresState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        *data.world.write_resource::<CurrentState>() = CurrentState::HighScores;
+        self.high_scores = read_high_scores();
+        let world = data.world;
+        initialise_camera(world);
+        let font = world.read_resource::<Loader>().load(
+            "fonts/pixel.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        );
+        self.title_text = Some(add_text(world, font.clone(), 280., 50.));
+        for rank in 0..HIGH_SCORES_LEN {
+            let y = 190. - rank as f32 * 45.;
+            self.row_texts.push(add_text(world, font.clone(), y, 35.));
+        }
+        self.online_text = Some(add_text(world, font, -270., 30.));
+        self.refresh(world);
+    }
+
+    fn update(&mut self, data: &mut StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        let responses = data.world.read_resource::<Leaderboard>().poll();
+        for response in responses {
+            let label = match (response, self.table()) {
+                (LeaderboardResponse::Top { level, runs }, HighScoreTable::Level(level_nb))
+                    if level == level_nb =>
+                {
+                    match runs.first() {
+                        Some(run) => format!("Online best: {} in {:.1}s", run.score, run.time),
+                        None => "Online best: -".to_string(),
+                    }
+                }
+                (LeaderboardResponse::Failed(_), HighScoreTable::Level(_)) => {
+                    "Online best: unavailable".to_string()
+                }
+                _ => continue,
+            };
+            self.set_online_label(data.world, label);
+        }
+        Trans::None
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = event {
+            let table_count = MAX_LVL + 2;
+            if is_key_down(&event, VirtualKeyCode::Left) {
+                self.table_index = (self.table_index + table_count - 1) % table_count;
+            } else if is_key_down(&event, VirtualKeyCode::Right) {
+                self.table_index = (self.table_index + 1) % table_count;
+            } else if is_key_down(&event, VirtualKeyCode::Escape)
+                || is_key_down(&event, VirtualKeyCode::Return)
+            {
+                data.world.create_entity().with(MenuSound).build();
+                return Trans::Switch(Box::new(MainMenuState::default()));
+            } else {
+                return Trans::None;
+            }
+            data.world.create_entity().with(MenuSound).build();
+            self.refresh(data.world);
+        }
+        Trans::None
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.delete_all();
+        *data.world.write_resource::<CurrentState>() = CurrentState::MainMenu;
+    }
+}
+
+fn initialise_camera(world: &mut World) {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(0., 0., 1.1);
+    world
+        .create_entity()
+        .with(Camera::standard_2d(SCREEN_WIDTH, SCREEN_HEIGHT))
+        .with(transform)
+        .build();
+}
+
+fn add_text(world: &mut World, font: Handle<FontAsset>, y: f32, font_size: f32) -> Entity {
+    let transform = UiTransform::new(
+        "high_scores".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        y,
+        1.,
+        700.,
+        font_size + 10.,
+    );
+    world
+        .create_entity()
+        .with(transform)
+        .with(UiText::new(
+            font,
+            String::new(),
+            [1., 1., 1., 1.],
+            font_size,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build()
+}
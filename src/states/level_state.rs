@@ -1,41 +1,79 @@
-use amethyst::assets::Handle;
-use amethyst::core::ecs::{Builder, Entity, World, WorldExt};
-use amethyst::core::{Parent, Transform};
+use amethyst::assets::{Handle, Loader};
+use amethyst::core::ecs::{Builder, Entity, Join, World, WorldExt};
+use amethyst::core::{Parent, Time, Transform};
+use amethyst::renderer::palette::Srgba;
+use amethyst::renderer::resources::Tint;
+use amethyst::renderer::transparent::Transparent as RenderTransparent;
 use amethyst::renderer::{Camera, SpriteRender, SpriteSheet};
 use amethyst::{GameData, SimpleState, SimpleTrans, StateData, Trans};
 
-use crate::entities::blade_saw::BladeSawSprite;
+use crate::entities::blade_saw::{BladeSawHazard, BladeSawSprite};
 use crate::entities::bonus::Bonus;
-use crate::entities::collision::{Arrival, LandingPlatform};
+use crate::entities::camera::CameraFollow;
+use crate::entities::canons::Canon;
+use crate::entities::cargo::Cargo;
+use crate::entities::collision::{
+    Arrival, Checkpoint, Colliders, LandingPlatform, MovingPlatform, OneWay, PadKind,
+    SecretPassage, TileBehaviour, Transparent, Waypoint,
+};
 use crate::entities::doors::{DoorState, PlasmaDoor};
+use crate::entities::particle::{ParticleEmitter, ParticlePreset, PARTICLE_SPRITE};
+use crate::entities::path::{PathFollower, PathKind};
 use crate::entities::ship::{
-    Coin, Ship, ShipFuel, ShipLife, ShipParent, ShipPowerLeftNumber, ShipPowerRightNumber,
-    Thrusters,
+    Coin, ObjectivesHud, Ship, ShipFuel, ShipLife, ShipParent, ShipPowerLeftNumber,
+    ShipPowerRightNumber, ShipsLeft, StrandedPrompt, Thrusters,
 };
+use crate::entities::target::Target;
+use crate::entities::zone::{ForceZone, ZoneParticle};
+use crate::resources::camera_effects::{CameraEffects, CameraSettings};
+use crate::resources::campaign::Campaign;
+use crate::resources::difficulty::Difficulty;
+use crate::resources::fuel::FuelStatus;
 use crate::resources::main_resource::{MainResource, MainSprites};
+use crate::resources::objectives::Objectives;
+use crate::resources::particles::ParticleBursts;
+use crate::resources::score::ScoreTracker;
+use crate::resources::spatial_index::SpatialIndex;
+use crate::resources::stats::ShipStats;
 use crate::states::end_state::EndLevelState;
+use crate::states::game_over_state::GameOverState;
 use crate::states::next_level::NextLevelState;
+use crate::states::score_tally_state::ScoreTallyState;
 use crate::states::CurrentState;
 use crate::utils::level_reader::{read_level, LevelConfig};
+use crate::utils::save::save_progress;
 use crate::utils::sprites::plasma_doors::is_plasma_door_part;
 use crate::utils::sprites::sprite_to_entities::{
-    is_arrival, is_landing_platform_start, sprite_to_bonus_kind, sprite_to_canon,
-    sprite_to_colliders, BLADE_SAW_SPRITE,
+    canon_kind_to_sprite, init_mounted_canon_collider, init_pad_collider, is_arrival,
+    is_landing_platform_start, sprite_to_bonus_kind, sprite_to_canon, sprite_to_colliders,
+    turret_kind_to_sprite, BLADE_SAW_SPRITE, CARGO, PAD_LEFT, PAD_RIGHT, TARGET,
 };
 use crate::utils::sprites::*;
 use amethyst::core::math::Point3;
-use amethyst::ui::{Anchor, ScaleMode, UiCreator, UiImage, UiTransform};
+use amethyst::ui::{
+    Anchor, LineMode, ScaleMode, TtfFormat, UiCreator, UiImage, UiText, UiTransform,
+};
 use amethyst::utils::application_root_dir;
+use rand::Rng;
 use serde_json::from_reader;
 use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_2;
 use std::fs::File;
 
 pub struct LevelState {
     pub level_nb: usize,
 }
 
-const MAX_LVL: usize = 10;
+pub const MAX_LVL: usize = 10;
+const CANON_MUZZLE_LENGTH: f32 = 16.;
+const ZONE_AREA_PER_PARTICLE: f32 = 64. * 64.;
+const CHECKPOINT_IDLE_TINT: (f32, f32, f32) = (0.6, 0.6, 0.6);
+const REFUEL_PAD_TINT: (f32, f32, f32) = (1., 0.75, 0.3);
+const REPAIR_PAD_TINT: (f32, f32, f32) = (0.4, 0.7, 1.);
+const DELIVERY_PAD_TINT: (f32, f32, f32) = (0.5, 1., 0.5);
+const CARGO_TINT: (f32, f32, f32) = (0.5, 1., 0.5);
+const TARGET_TINT: (f32, f32, f32) = (1., 0.3, 0.3);
 
 impl SimpleState for LevelState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
@@ -46,26 +84,60 @@ impl SimpleState for LevelState {
 
     fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         data.world.delete_all();
+        // A hit-stop could still be running when the level is left.
+        data.world.write_resource::<Time>().set_time_scale(1.);
         *data.world.write_resource::<CurrentState>() = CurrentState::Level;
     }
 
     fn fixed_update(&mut self, data: StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
         let world = data.world;
-        let (victory, current_level, should_go_to_next_level) = {
+        let (
+            victory,
+            current_level,
+            should_go_to_next_level,
+            campaign_failed,
+            game_over,
+            difficulty,
+        ) = {
             let resource = world.read_resource::<MainResource>();
             (
                 resource.victory,
                 resource.current_level,
                 resource.should_go_to_next_level,
+                resource.campaign_failed,
+                resource.game_over,
+                resource.difficulty,
             )
         };
+        if campaign_failed || game_over {
+            let finished_campaign = *world.read_resource::<Campaign>();
+            let campaign = Campaign::new(finished_campaign.arcade);
+            world.insert(campaign);
+            save_progress(0, difficulty, campaign);
+            if game_over {
+                return Trans::Switch(Box::new(GameOverState::new(finished_campaign.total_score)));
+            }
+            return Trans::Switch(Box::new(NextLevelState::new(0)));
+        }
         if victory && should_go_to_next_level {
             let new_level = current_level + 1;
-            if new_level <= MAX_LVL {
-                return Trans::Switch(Box::new(NextLevelState::new(new_level)));
+            let next_level = if new_level <= MAX_LVL {
+                Some(new_level)
             } else {
-                return Trans::Switch(Box::new(EndLevelState));
-            }
+                None
+            };
+            let breakdown = world.write_resource::<ScoreTracker>().breakdown.take();
+            return match breakdown {
+                Some(breakdown) => Trans::Switch(Box::new(ScoreTallyState::new(
+                    current_level,
+                    next_level,
+                    breakdown,
+                ))),
+                None => match next_level {
+                    Some(next_level) => Trans::Switch(Box::new(NextLevelState::new(next_level))),
+                    None => Trans::Switch(Box::new(EndLevelState)),
+                },
+            };
         }
         return Trans::None;
     }
@@ -82,6 +154,8 @@ fn load_level(lvl_number: usize, world: &mut World) {
 
     initialize_level_tileset(world, level_spritesheet_handle, &level);
     initialize_colliders_with_entitites(world, &level, misc_spritesheet_handle.clone());
+    initialize_zones(world, &level, bullet_spritesheet_handle.clone());
+    initialize_spatial_index(world);
     let ship = initialize_ship(world, &level, ship_spritesheet_handle);
     initialize_camera(world, ship);
     world.exec(|mut creator: UiCreator<'_>| {
@@ -91,12 +165,37 @@ fn load_level(lvl_number: usize, world: &mut World) {
     initialize_power_ui(world, numbers_spritesheet_handle);
     initialize_life_and_fuel_ui(world);
     initialize_coins_ui(world, &level, misc_spritesheet_handle);
-    let mut ship_resource = MainResource::new_from_level(Some(level), lvl_number);
+    initialize_ships_left_ui(world);
+    initialize_stranded_prompt(world);
+    initialize_objectives_ui(world);
+    world.insert(Objectives::new(&level));
+    let difficulty = *world.read_resource::<Difficulty>();
+    let mut ship_resource = MainResource::new_from_level(Some(level), lvl_number, difficulty);
     ship_resource.sprites = Some(MainSprites {
         explosion_sprite_render: ship_explosion_handle,
         bullet_sprite_render: bullet_spritesheet_handle,
     });
     world.insert(ship_resource);
+    world.insert(ShipStats::default());
+    world.insert(ScoreTracker::default());
+    world.insert(CameraEffects::default());
+    world.insert(ParticleBursts::default());
+    world.insert(FuelStatus {
+        gauge_visible: true,
+        ..FuelStatus::default()
+    });
+}
+
+fn initialize_spatial_index(world: &mut World) {
+    let mut spatial_index = SpatialIndex::default();
+    {
+        let entities = world.entities();
+        let colliders = world.read_storage::<Colliders>();
+        for (entity, collider) in (&entities, &colliders).join() {
+            spatial_index.insert(entity, collider);
+        }
+    }
+    world.insert(spatial_index);
 }
 
 fn initialize_colliders_with_entitites(
@@ -104,6 +203,7 @@ fn initialize_colliders_with_entitites(
     level: &LevelConfig,
     sprite_sheet_handle: Handle<SpriteSheet>,
 ) {
+    let secret_passages = level.secret_passages();
     for (point, sprite) in level.tiles.borrow() {
         if let Some(bonus) = sprite_to_bonus_kind(*sprite) {
             let mut transform = Transform::default();
@@ -128,6 +228,30 @@ fn initialize_colliders_with_entitites(
                 .build();
         }
 
+        let behaviour = level.tile_behaviours.get(point);
+        if let Some(passage) = secret_passages.get(point) {
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(
+                point.x as f32 * TILE_SIZE,
+                point.y as f32 * TILE_SIZE,
+                0.7,
+            );
+            world
+                .create_entity()
+                .with(SecretPassage {
+                    passage: *passage,
+                    alpha: 1.,
+                })
+                .with(SpriteRender {
+                    sprite_sheet: sprite_sheet_handle.clone(),
+                    sprite_number: *sprite,
+                })
+                .with(Tint(Srgba::new(1., 1., 1., 1.)))
+                .with(RenderTransparent)
+                .with(transform)
+                .build();
+        }
+
         let collider = sprite_to_colliders(
             *sprite,
             point.x as f32 * TILE_SIZE,
@@ -154,15 +278,31 @@ fn initialize_colliders_with_entitites(
                     })
                     .with(transform);
             }
-            if is_landing_platform_start(*sprite) {
-                builder = builder.with(LandingPlatform);
-            }
             if is_arrival(*sprite) {
-                builder = builder.with(Arrival);
+                builder = builder
+                    .with(LandingPlatform {
+                        kind: PadKind::Arrival,
+                    })
+                    .with(Arrival);
+            } else if is_landing_platform_start(*sprite) {
+                builder = builder.with(LandingPlatform {
+                    kind: PadKind::Start,
+                });
             }
             if let Some(canon) = sprite_to_canon(*sprite, point.x as usize, point.y as usize) {
                 builder = builder.with(canon);
             }
+            match behaviour {
+                Some(TileBehaviour::Transparent) | Some(TileBehaviour::Secret) => {
+                    builder = builder.with(Transparent);
+                }
+                Some(TileBehaviour::OneWay(direction)) => {
+                    builder = builder.with(OneWay {
+                        direction: *direction,
+                    });
+                }
+                None => {}
+            }
             builder.build();
         }
     }
@@ -177,22 +317,277 @@ fn initialize_colliders_with_entitites(
         let parent = world
             .create_entity()
             .with(blade_saw.clone())
+            .with(BladeSawHazard)
             .with(parent_transform)
             .build();
+        initialize_blade_saw_sprite(world, parent, sprite_sheet_handle.clone());
+    }
+
+    for path in level.paths.iter() {
+        let follower = PathFollower::new(path);
+        let (x, y) = follower.position();
+        let mut parent_transform = Transform::default();
+        parent_transform.set_translation_xyz(x, y, 0.6);
+        match &path.kind {
+            PathKind::BladeSaw => {
+                let parent = world
+                    .create_entity()
+                    .with(follower)
+                    .with(BladeSawHazard)
+                    .with(parent_transform)
+                    .build();
+                initialize_blade_saw_sprite(world, parent, sprite_sheet_handle.clone());
+            }
+            PathKind::Platform(kind) => {
+                let (r, g, b) = pad_tint(*kind);
+                let parent = world
+                    .create_entity()
+                    .with(follower)
+                    .with(MovingPlatform::new(x, y))
+                    .with(LandingPlatform { kind: *kind })
+                    .with(init_pad_collider(x, y))
+                    .with(SpriteRender {
+                        sprite_sheet: sprite_sheet_handle.clone(),
+                        sprite_number: PAD_LEFT,
+                    })
+                    .with(Tint(Srgba::new(r, g, b, 1.)))
+                    .with(parent_transform)
+                    .build();
+                let mut transform = Transform::default();
+                transform.set_translation_xyz(TILE_SIZE, 0., 0.);
+                world
+                    .create_entity()
+                    .with(SpriteRender {
+                        sprite_sheet: sprite_sheet_handle.clone(),
+                        sprite_number: PAD_RIGHT,
+                    })
+                    .with(Tint(Srgba::new(r, g, b, 1.)))
+                    .with(transform)
+                    .with(Parent { entity: parent })
+                    .build();
+            }
+            PathKind::Drone(turret) => {
+                parent_transform.set_rotation_z_axis(turret.angle);
+                world
+                    .create_entity()
+                    .with(follower)
+                    .with(turret.clone())
+                    .with(init_mounted_canon_collider(x, y))
+                    .with(SpriteRender {
+                        sprite_sheet: sprite_sheet_handle.clone(),
+                        sprite_number: turret_kind_to_sprite(&turret.kind),
+                    })
+                    .with(parent_transform)
+                    .build();
+            }
+        }
+    }
+
+    for turret in level.turrets.iter() {
+        let (x, y) = (turret.x * TILE_SIZE, turret.y * TILE_SIZE);
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(x, y, 0.6);
+        transform.set_rotation_z_axis(turret.angle);
+        world
+            .create_entity()
+            .with(turret.clone())
+            .with(init_mounted_canon_collider(x, y))
+            .with(SpriteRender {
+                sprite_sheet: sprite_sheet_handle.clone(),
+                sprite_number: turret_kind_to_sprite(&turret.kind),
+            })
+            .with(transform)
+            .build();
+    }
 
+    for canon in level.canons.iter() {
+        let (x, y) = (canon.x * TILE_SIZE, canon.y * TILE_SIZE);
         let mut transform = Transform::default();
+        transform.set_translation_xyz(x, y, 0.6);
+        transform.set_rotation_z_axis(canon.angle);
+        world
+            .create_entity()
+            .with(Canon {
+                angle: canon.angle,
+                kind: canon.kind.clone(),
+                bullet_x_start: x + CANON_MUZZLE_LENGTH * canon.angle.cos(),
+                bullet_y_start: y + CANON_MUZZLE_LENGTH * canon.angle.sin(),
+            })
+            .with(init_mounted_canon_collider(x, y))
+            .with(SpriteRender {
+                sprite_sheet: sprite_sheet_handle.clone(),
+                sprite_number: canon_kind_to_sprite(&canon.kind),
+            })
+            .with(transform)
+            .build();
+    }
 
+    for (x, y) in level.checkpoints.iter() {
+        let pad = initialize_pad(
+            world,
+            *x,
+            *y,
+            PadKind::Checkpoint,
+            CHECKPOINT_IDLE_TINT,
+            sprite_sheet_handle.clone(),
+        );
+        world
+            .write_storage::<Checkpoint>()
+            .insert(pad, Checkpoint { reached: false })
+            .expect("Failed to mark the checkpoint");
+    }
+    for pad in level.pads.iter() {
+        let entity = initialize_pad(
+            world,
+            pad.x,
+            pad.y,
+            pad.kind,
+            pad_tint(pad.kind),
+            sprite_sheet_handle.clone(),
+        );
+        if let Some(order) = pad.visit {
+            world
+                .write_storage::<Waypoint>()
+                .insert(entity, Waypoint { order })
+                .expect("Failed to mark the waypoint");
+        }
+    }
+    for cargo in level.cargos.iter() {
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(cargo.x, cargo.y, 0.8);
+        let (r, g, b) = CARGO_TINT;
         world
             .create_entity()
-            .with(BladeSawSprite)
+            .with(Cargo::new(
+                cargo.order,
+                cargo.mass,
+                cargo.tethered,
+                cargo.x,
+                cargo.y,
+            ))
             .with(SpriteRender {
                 sprite_sheet: sprite_sheet_handle.clone(),
-                sprite_number: BLADE_SAW_SPRITE,
+                sprite_number: CARGO,
             })
+            .with(Tint(Srgba::new(r, g, b, 1.)))
             .with(transform)
-            .with(Parent { entity: parent })
             .build();
     }
+    for (x, y) in level.targets.iter() {
+        let mut transform = Transform::default();
+        transform.set_translation_xyz(*x, *y, 0.6);
+        let (r, g, b) = TARGET_TINT;
+        world
+            .create_entity()
+            .with(Target)
+            .with(SpriteRender {
+                sprite_sheet: sprite_sheet_handle.clone(),
+                sprite_number: TARGET,
+            })
+            .with(Tint(Srgba::new(r, g, b, 1.)))
+            .with(transform)
+            .build();
+    }
+}
+
+fn initialize_pad(
+    world: &mut World,
+    x: f32,
+    y: f32,
+    kind: PadKind,
+    (r, g, b): (f32, f32, f32),
+    sprite_sheet_handle: Handle<SpriteSheet>,
+) -> Entity {
+    let mut parent_transform = Transform::default();
+    parent_transform.set_translation_xyz(x, y, 0.6);
+    let parent = world
+        .create_entity()
+        .with(LandingPlatform { kind })
+        .with(init_pad_collider(x, y))
+        .with(SpriteRender {
+            sprite_sheet: sprite_sheet_handle.clone(),
+            sprite_number: PAD_LEFT,
+        })
+        .with(Tint(Srgba::new(r, g, b, 1.)))
+        .with(parent_transform)
+        .build();
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(TILE_SIZE, 0., 0.);
+    world
+        .create_entity()
+        .with(SpriteRender {
+            sprite_sheet: sprite_sheet_handle,
+            sprite_number: PAD_RIGHT,
+        })
+        .with(Tint(Srgba::new(r, g, b, 1.)))
+        .with(transform)
+        .with(Parent { entity: parent })
+        .build();
+    parent
+}
+
+fn pad_tint(kind: PadKind) -> (f32, f32, f32) {
+    match kind {
+        PadKind::Refuel { .. } => REFUEL_PAD_TINT,
+        PadKind::Repair => REPAIR_PAD_TINT,
+        PadKind::Delivery { .. } => DELIVERY_PAD_TINT,
+        _ => (1., 1., 1.),
+    }
+}
+
+fn initialize_zones(
+    world: &mut World,
+    level: &LevelConfig,
+    sprite_sheet_handle: Handle<SpriteSheet>,
+) {
+    let mut rng = rand::thread_rng();
+    for config in level.zones.iter() {
+        let zone = ForceZone::new(config);
+        if config.particles {
+            let bounds = zone.bounds.translate(-TILE_SIZE / 2., TILE_SIZE / 2.);
+            let particle_nb = ((bounds.width() * bounds.height()) / ZONE_AREA_PER_PARTICLE)
+                .max(3.)
+                .min(30.) as usize;
+            for _ in 0..particle_nb {
+                let mut transform = Transform::default();
+                transform.set_translation_xyz(
+                    rng.gen_range(bounds.min_x, bounds.max_x),
+                    rng.gen_range(bounds.min_y, bounds.max_y),
+                    0.5,
+                );
+                world
+                    .create_entity()
+                    .with(ZoneParticle {
+                        zone_kind: zone.kind.clone(),
+                        bounds,
+                    })
+                    .with(SpriteRender {
+                        sprite_sheet: sprite_sheet_handle.clone(),
+                        sprite_number: PARTICLE_SPRITE,
+                    })
+                    .with(transform)
+                    .build();
+            }
+        }
+        world.create_entity().with(zone).build();
+    }
+}
+
+fn initialize_blade_saw_sprite(
+    world: &mut World,
+    parent: Entity,
+    sprite_sheet_handle: Handle<SpriteSheet>,
+) {
+    world
+        .create_entity()
+        .with(BladeSawSprite)
+        .with(SpriteRender {
+            sprite_sheet: sprite_sheet_handle,
+            sprite_number: BLADE_SAW_SPRITE,
+        })
+        .with(Transform::default())
+        .with(Parent { entity: parent })
+        .build();
 }
 
 fn initialize_level_tileset(
@@ -255,6 +650,7 @@ fn initialize_ship(
         .create_entity()
         .with(thrusters_sprite_render)
         .with(Thrusters)
+        .with(ParticleEmitter::new(ParticlePreset::Exhaust, -FRAC_PI_2))
         .with(transform_thruster)
         .with(Parent { entity: ship })
         .build();
@@ -263,13 +659,19 @@ fn initialize_ship(
 }
 
 pub fn initialize_camera(world: &mut World, ship: Entity) {
+    let (x, y) = world
+        .read_storage::<Transform>()
+        .get(ship)
+        .map(|transform| (transform.translation().x, transform.translation().y))
+        .unwrap_or((0., 0.));
+    let follow = CameraFollow::new(&world.read_resource::<CameraSettings>());
     let mut transform = Transform::default();
-    transform.set_translation_xyz(0., 0., 1.1);
+    transform.set_translation_xyz(x, y, 1.1);
     world
         .create_entity()
         .with(Camera::standard_2d(SCREEN_WIDTH, SCREEN_HEIGHT))
         .with(transform)
-        .with(Parent { entity: ship })
+        .with(follow)
         .build();
 }
 
@@ -377,7 +779,8 @@ fn format_lvl_number(lvl_number: usize) -> (usize, usize) {
 
 fn initialize_life_and_fuel_ui(world: &mut World) {
     let power_spritesheet_handle = load_power_spritesheet(world);
-    for life_point in 0..3 {
+    let max_life = world.read_resource::<Difficulty>().ship_life();
+    for life_point in 0..max_life {
         let mut life_point_transform = UiTransform::new(
             format!("life_{}", life_point.to_string()),
             Anchor::BottomLeft,
@@ -393,7 +796,7 @@ fn initialize_life_and_fuel_ui(world: &mut World) {
             .create_entity()
             .with(life_point_transform)
             .with(ShipLife {
-                life_point: life_point as u8 + 1,
+                life_point: life_point + 1,
             })
             .with(UiImage::Sprite(SpriteRender {
                 sprite_sheet: power_spritesheet_handle.clone(),
@@ -452,3 +855,105 @@ fn initialize_coins_ui(world: &mut World, config: &LevelConfig, spritesheet: Han
             .build();
     }
 }
+
+fn initialize_ships_left_ui(world: &mut World) {
+    if !world.read_resource::<Campaign>().arcade {
+        return;
+    }
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let mut transform = UiTransform::new(
+        "ships_left".to_string(),
+        Anchor::BottomLeft,
+        Anchor::BottomLeft,
+        0.53977272727,
+        0.01215277777,
+        10.,
+        0.11363636363,
+        0.04166666666,
+    );
+    transform.scale_mode = ScaleMode::Percent;
+    world
+        .create_entity()
+        .with(transform)
+        .with(ShipsLeft)
+        .with(UiText::new(
+            font,
+            String::new(),
+            [1., 1., 1., 1.],
+            24.,
+            LineMode::Single,
+            Anchor::MiddleLeft,
+        ))
+        .build();
+}
+
+fn initialize_stranded_prompt(world: &mut World) {
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let mut transform = UiTransform::new(
+        "stranded_prompt".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        0.15,
+        10.,
+        0.9,
+        0.06,
+    );
+    transform.scale_mode = ScaleMode::Percent;
+    world
+        .create_entity()
+        .with(transform)
+        .with(StrandedPrompt)
+        .with(UiText::new(
+            font,
+            String::new(),
+            [1., 0.4, 0.3, 1.],
+            28.,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build();
+}
+
+fn initialize_objectives_ui(world: &mut World) {
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let mut transform = UiTransform::new(
+        "objectives".to_string(),
+        Anchor::TopLeft,
+        Anchor::TopLeft,
+        0.01,
+        -0.01,
+        10.,
+        0.4,
+        0.2,
+    );
+    transform.scale_mode = ScaleMode::Percent;
+    world
+        .create_entity()
+        .with(transform)
+        .with(ObjectivesHud)
+        .with(UiText::new(
+            font,
+            String::new(),
+            [1., 1., 1., 1.],
+            20.,
+            LineMode::Wrap,
+            Anchor::TopLeft,
+        ))
+        .build();
+}
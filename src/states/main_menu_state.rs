@@ -1,6 +1,9 @@
 use crate::entities::main_menu::{MenuBackground, PushEnter};
 use crate::entities::ship::Ship;
 use crate::entities::sound::MenuSound;
+use crate::resources::campaign::Campaign;
+use crate::resources::difficulty::Difficulty;
+use crate::states::high_scores_state::HighScoresState;
 use crate::states::next_level::NextLevelState;
 use crate::states::CurrentState;
 use crate::utils::save::{read_saved_level, StarlightSave};
@@ -9,12 +12,14 @@ use crate::utils::sprites::{
     load_background, load_background_2, load_background_3, load_menu_spritesheet, load_push_enter,
     load_ship_thrusters_spritesheet, SCREEN_HEIGHT, SCREEN_WIDTH,
 };
-use amethyst::assets::Handle;
+use amethyst::assets::{Handle, Loader};
 use amethyst::core::ecs::{Builder, Entity, World, WorldExt};
 use amethyst::core::Transform;
 use amethyst::input::{is_key_down, VirtualKeyCode};
 use amethyst::renderer::{Camera, SpriteRender, SpriteSheet};
-use amethyst::ui::{Anchor, ScaleMode, UiCreator, UiImage, UiTransform};
+use amethyst::ui::{
+    Anchor, LineMode, ScaleMode, TtfFormat, UiCreator, UiImage, UiText, UiTransform,
+};
 use amethyst::{GameData, SimpleState, SimpleTrans, StateData, StateEvent, Trans};
 
 pub struct MainMenuState {
@@ -23,6 +28,10 @@ pub struct MainMenuState {
     saved_level_progress: Option<StarlightSave>,
     arrow: Option<Entity>,
     menu_position: usize,
+    difficulty: Difficulty,
+    difficulty_text: Option<Entity>,
+    arcade: bool,
+    arcade_text: Option<Entity>,
 }
 
 impl Default for MainMenuState {
@@ -33,6 +42,10 @@ impl Default for MainMenuState {
             saved_level_progress: None,
             arrow: None,
             menu_position: 0,
+            difficulty: Difficulty::default(),
+            difficulty_text: None,
+            arcade: false,
+            arcade_text: None,
         }
     }
 }
@@ -40,7 +53,13 @@ impl Default for MainMenuState {
 impl SimpleState for MainMenuState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         self.saved_level_progress = read_saved_level();
+        if let Some(save) = self.saved_level_progress.as_ref() {
+            self.difficulty = save.difficulty;
+            self.arcade = save.campaign.arcade;
+        }
         let world = data.world;
+        world.insert(self.difficulty);
+        world.insert(Campaign::new(self.arcade));
         *world.write_resource::<CurrentState>() = CurrentState::MainMenu;
         world.exec(|mut creator: UiCreator<'_>| {
             creator.create("ui/title.ron", ());
@@ -76,13 +95,20 @@ impl SimpleState for MainMenuState {
                         data.world,
                         &self.saved_level_progress,
                     ));
+                    self.difficulty_text = Some(add_difficulty_text(data.world, self.difficulty));
+                    self.arcade_text = Some(add_arcade_text(data.world, self.arcade));
+                    add_high_scores_hint(data.world);
                 } else if self.saved_level_progress.is_some() && self.menu_position == 0 {
                     data.world.insert(MenuSound);
-                    return Trans::Switch(Box::new(NextLevelState::new(
-                        self.saved_level_progress.as_ref().unwrap().save,
-                    )));
+                    // A campaign keeps the difficulty it was started on.
+                    let save = self.saved_level_progress.as_ref().unwrap();
+                    data.world.insert(save.difficulty);
+                    data.world.insert(save.campaign);
+                    return Trans::Switch(Box::new(NextLevelState::new(save.save)));
                 } else {
                     data.world.create_entity().with(MenuSound).build();
+                    data.world.insert(self.difficulty);
+                    data.world.insert(Campaign::new(self.arcade));
                     return Trans::Switch(Box::new(NextLevelState::new(0)));
                 }
             } else if is_key_down(&event, VirtualKeyCode::Up) {
@@ -99,6 +125,31 @@ impl SimpleState for MainMenuState {
                     self.menu_position = 1;
                     self.arrow = Some(init_arrow(data.world, 1));
                 }
+            } else if is_key_down(&event, VirtualKeyCode::Left)
+                || is_key_down(&event, VirtualKeyCode::Right)
+            {
+                if let Some(text_entity) = self.difficulty_text {
+                    data.world.create_entity().with(MenuSound).build();
+                    self.difficulty = if is_key_down(&event, VirtualKeyCode::Left) {
+                        self.difficulty.previous()
+                    } else {
+                        self.difficulty.next()
+                    };
+                    if let Some(text) = data.world.write_storage::<UiText>().get_mut(text_entity) {
+                        text.text = difficulty_label(self.difficulty);
+                    }
+                }
+            } else if is_key_down(&event, VirtualKeyCode::H) {
+                data.world.create_entity().with(MenuSound).build();
+                return Trans::Switch(Box::new(HighScoresState::default()));
+            } else if is_key_down(&event, VirtualKeyCode::A) {
+                if let Some(text_entity) = self.arcade_text {
+                    data.world.create_entity().with(MenuSound).build();
+                    self.arcade = !self.arcade;
+                    if let Some(text) = data.world.write_storage::<UiText>().get_mut(text_entity) {
+                        text.text = arcade_label(self.arcade);
+                    }
+                }
             }
         }
         Trans::None
@@ -263,3 +314,108 @@ fn init_arrow(world: &mut World, shif: usize) -> Entity {
         .with(transform_arrow)
         .build()
 }
+
+fn difficulty_label(difficulty: Difficulty) -> String {
+    format!("< {} >", difficulty.name())
+}
+
+fn add_difficulty_text(world: &mut World, difficulty: Difficulty) -> Entity {
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let transform = UiTransform::new(
+        "difficulty".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        -220.,
+        10.,
+        400.,
+        60.,
+    );
+    world
+        .create_entity()
+        .with(transform)
+        .with(UiText::new(
+            font,
+            difficulty_label(difficulty),
+            [1., 1., 1., 1.],
+            40.,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build()
+}
+
+fn arcade_label(arcade: bool) -> String {
+    if arcade {
+        "Arcade: On (A)".to_string()
+    } else {
+        "Arcade: Off (A)".to_string()
+    }
+}
+
+fn add_arcade_text(world: &mut World, arcade: bool) -> Entity {
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let transform = UiTransform::new(
+        "arcade".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        -270.,
+        10.,
+        400.,
+        40.,
+    );
+    world
+        .create_entity()
+        .with(transform)
+        .with(UiText::new(
+            font,
+            arcade_label(arcade),
+            [1., 1., 1., 1.],
+            25.,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build()
+}
+
+fn add_high_scores_hint(world: &mut World) {
+    let font = world.read_resource::<Loader>().load(
+        "fonts/pixel.ttf",
+        TtfFormat,
+        (),
+        &world.read_resource(),
+    );
+    let transform = UiTransform::new(
+        "high_scores_hint".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        -310.,
+        10.,
+        400.,
+        40.,
+    );
+    world
+        .create_entity()
+        .with(transform)
+        .with(UiText::new(
+            font,
+            "High scores (H)".to_string(),
+            [1., 1., 1., 1.],
+            25.,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build();
+}
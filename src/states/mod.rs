@@ -1,7 +1,11 @@
 pub mod end_state;
+pub mod game_over_state;
+pub mod high_scores_state;
 pub mod level_state;
 pub mod main_menu_state;
+pub mod name_entry_state;
 pub mod next_level;
+pub mod score_tally_state;
 
 #[derive(PartialEq)]
 pub enum CurrentState {
@@ -9,6 +13,9 @@ pub enum CurrentState {
     Level,
     NextLevel,
     End,
+    GameOver,
+    Tally,
+    HighScores,
 }
 
 impl Default for CurrentState {
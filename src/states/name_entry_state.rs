@@ -0,0 +1,221 @@
+use crate::entities::sound::MenuSound;
+use crate::states::end_state::EndLevelState;
+use crate::states::main_menu_state::MainMenuState;
+use crate::states::next_level::NextLevelState;
+use crate::states::CurrentState;
+use crate::utils::high_scores::{
+    read_high_scores, save_high_scores, HighScoreEntry, HighScoreTable, NAME_LEN,
+};
+use crate::utils::sprites::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use amethyst::assets::{Handle, Loader};
+use amethyst::core::ecs::{Builder, Entity, World, WorldExt};
+use amethyst::core::Transform;
+use amethyst::input::{is_key_down, VirtualKeyCode};
+use amethyst::renderer::Camera;
+use amethyst::ui::{Anchor, FontAsset, LineMode, TtfFormat, UiText, UiTransform};
+use amethyst::{GameData, SimpleState, SimpleTrans, StateData, StateEvent, Trans};
+
+const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Where the game goes once every pending name has been entered.
+pub enum AfterEntry {
+    NextLevel(usize),
+    End,
+    MainMenu,
+}
+
+impl AfterEntry {
+    fn into_trans(self) -> SimpleTrans {
+        match self {
+            AfterEntry::NextLevel(level_nb) => {
+                Trans::Switch(Box::new(NextLevelState::new(level_nb)))
+            }
+            AfterEntry::End => Trans::Switch(Box::new(EndLevelState)),
+            AfterEntry::MainMenu => Trans::Switch(Box::new(MainMenuState::default())),
+        }
+    }
+}
+
+/// Asks for a name for each score that makes it into its table, then moves on.
+pub fn enter_high_scores(pending: Vec<(HighScoreTable, u32)>, after: AfterEntry) -> SimpleTrans {
+    let high_scores = read_high_scores();
+    let pending: Vec<(HighScoreTable, u32)> = pending
+        .into_iter()
+        .filter(|(table, score)| high_scores.qualifies(*table, *score))
+        .collect();
+    if pending.is_empty() {
+        return after.into_trans();
+    }
+    Trans::Switch(Box::new(NameEntryState {
+        pending,
+        after: Some(after),
+        letters: [0; NAME_LEN],
+        cursor: 0,
+        name_text: None,
+        title_text: None,
+    }))
+}
+
+pub struct NameEntryState {
+    pending: Vec<(HighScoreTable, u32)>,
+    after: Option<AfterEntry>,
+    letters: [usize; NAME_LEN],
+    cursor: usize,
+    name_text: Option<Entity>,
+    title_text: Option<Entity>,
+}
+
+impl NameEntryState {
+    fn name(&self) -> String {
+        self.letters
+            .iter()
+            .map(|letter| ALPHABET[*letter] as char)
+            .collect()
+    }
+
+    fn name_label(&self) -> String {
+        self.letters
+            .iter()
+            .enumerate()
+            .map(|(index, letter)| {
+                if index == self.cursor {
+                    format!("[{}]", ALPHABET[*letter] as char)
+                } else {
+                    format!(" {} ", ALPHABET[*letter] as char)
+                }
+            })
+            .collect()
+    }
+
+    fn title_label(&self) -> String {
+        let (table, score) = self.pending[0];
+        format!("{} - {}", table.title(), score)
+    }
+
+    fn refresh(&self, world: &mut World) {
+        let mut ui_texts = world.write_storage::<UiText>();
+        if let Some(entity) = self.name_text {
+            if let Some(text) = ui_texts.get_mut(entity) {
+                text.text = self.name_label();
+            }
+        }
+        if let Some(entity) = self.title_text {
+            if let Some(text) = ui_texts.get_mut(entity) {
+                text.text = self.title_label();
+            }
+        }
+    }
+}
+
+impl SimpleState for NameEntryState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        *data.world.write_resource::<CurrentState>() = CurrentState::HighScores;
+        let world = data.world;
+        initialise_camera(world);
+        let font = world.read_resource::<Loader>().load(
+            "fonts/pixel.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        );
+        add_text(
+            world,
+            font.clone(),
+            "New high score !".to_string(),
+            200.,
+            60.,
+        );
+        self.title_text = Some(add_text(world, font.clone(), self.title_label(), 80., 40.));
+        self.name_text = Some(add_text(world, font, self.name_label(), -60., 70.));
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+        event: StateEvent,
+    ) -> SimpleTrans {
+        if let StateEvent::Window(event) = event {
+            if is_key_down(&event, VirtualKeyCode::Up) {
+                self.letters[self.cursor] = (self.letters[self.cursor] + 1) % ALPHABET.len();
+            } else if is_key_down(&event, VirtualKeyCode::Down) {
+                self.letters[self.cursor] =
+                    (self.letters[self.cursor] + ALPHABET.len() - 1) % ALPHABET.len();
+            } else if is_key_down(&event, VirtualKeyCode::Left) {
+                self.cursor = self.cursor.saturating_sub(1);
+            } else if is_key_down(&event, VirtualKeyCode::Right) {
+                self.cursor = (self.cursor + 1).min(NAME_LEN - 1);
+            } else if is_key_down(&event, VirtualKeyCode::Return) {
+                if self.cursor < NAME_LEN - 1 {
+                    self.cursor += 1;
+                } else {
+                    let (table, score) = self.pending.remove(0);
+                    let mut high_scores = read_high_scores();
+                    high_scores.insert(
+                        table,
+                        HighScoreEntry {
+                            name: self.name(),
+                            score,
+                        },
+                    );
+                    save_high_scores(&high_scores);
+                    if self.pending.is_empty() {
+                        data.world.create_entity().with(MenuSound).build();
+                        return self.after.take().unwrap().into_trans();
+                    }
+                    self.cursor = 0;
+                }
+            } else {
+                return Trans::None;
+            }
+            data.world.create_entity().with(MenuSound).build();
+            self.refresh(data.world);
+        }
+        Trans::None
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.delete_all();
+        *data.world.write_resource::<CurrentState>() = CurrentState::NextLevel;
+    }
+}
+
+fn initialise_camera(world: &mut World) {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(0., 0., 1.1);
+    world
+        .create_entity()
+        .with(Camera::standard_2d(SCREEN_WIDTH, SCREEN_HEIGHT))
+        .with(transform)
+        .build();
+}
+
+fn add_text(
+    world: &mut World,
+    font: Handle<FontAsset>,
+    text: String,
+    y: f32,
+    font_size: f32,
+) -> Entity {
+    let transform = UiTransform::new(
+        "name_entry".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        y,
+        1.,
+        700.,
+        font_size + 10.,
+    );
+    world
+        .create_entity()
+        .with(transform)
+        .with(UiText::new(
+            font,
+            text,
+            [1., 1., 1., 1.],
+            font_size,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build()
+}
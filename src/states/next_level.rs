@@ -1,3 +1,5 @@
+use crate::resources::campaign::Campaign;
+use crate::resources::difficulty::Difficulty;
 use crate::states::level_state::LevelState;
 use crate::states::main_menu_state::{add_animated_backgrounds, add_ship};
 use crate::states::CurrentState;
@@ -31,7 +33,11 @@ impl SimpleState for NextLevelState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         *data.world.write_resource::<CurrentState>() = CurrentState::MainMenu;
         if self.next_level_nb > 0 {
-            save_progress(self.next_level_nb);
+            save_progress(
+                self.next_level_nb,
+                *data.world.read_resource::<Difficulty>(),
+                *data.world.read_resource::<Campaign>(),
+            );
         }
         let level = read_level(self.next_level_nb);
         let world = data.world;
@@ -67,6 +73,11 @@ fn initialise_camera(world: &mut World) {
         .build();
 }
 
+/// How levels are named everywhere they are shown to the player.
+pub fn level_title(level_nb: usize) -> String {
+    format!("Level {}", level_nb)
+}
+
 fn initialise_texts(world: &mut World, lvl_number: usize, config: LevelConfig) {
     let font = world.read_resource::<Loader>().load(
         "fonts/pixel.ttf",
@@ -95,7 +106,23 @@ fn initialise_texts(world: &mut World, lvl_number: usize, config: LevelConfig) {
         600.,
         400.,
     );
-    let text = format!("Level {}", lvl_number);
+    let objectives_transform = UiTransform::new(
+        "objectives".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        -280.,
+        1.,
+        600.,
+        200.,
+    );
+    let objectives = config
+        .objectives
+        .iter()
+        .map(|objective| objective.describe(&config))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let text = level_title(lvl_number);
     world
         .create_entity()
         .with(level_nb_transform)
@@ -120,4 +147,16 @@ fn initialise_texts(world: &mut World, lvl_number: usize, config: LevelConfig) {
             Anchor::Middle,
         ))
         .build();
+    world
+        .create_entity()
+        .with(objectives_transform)
+        .with(UiText::new(
+            font.clone(),
+            objectives,
+            [0.8, 0.8, 0.8, 1.],
+            30.,
+            LineMode::Wrap,
+            Anchor::Middle,
+        ))
+        .build();
 }
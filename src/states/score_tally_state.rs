@@ -0,0 +1,158 @@
+use crate::resources::campaign::Campaign;
+use crate::resources::score::ScoreBreakdown;
+use crate::states::name_entry_state::{enter_high_scores, AfterEntry};
+use crate::states::CurrentState;
+use crate::utils::high_scores::HighScoreTable;
+use crate::utils::save::record_best_score;
+use crate::utils::sprites::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use amethyst::assets::{Handle, Loader};
+use amethyst::core::ecs::{Builder, Entity, World, WorldExt};
+use amethyst::core::Transform;
+use amethyst::renderer::Camera;
+use amethyst::ui::{Anchor, FontAsset, LineMode, TtfFormat, UiText, UiTransform};
+use amethyst::{GameData, SimpleState, SimpleTrans, StateData, Trans};
+
+const FRAMES_PER_LINE: f32 = 30.;
+const FRAMES_AFTER_TALLY: f32 = 150.;
+
+pub struct ScoreTallyState {
+    level_nb: usize,
+    next_level_nb: Option<usize>,
+    breakdown: ScoreBreakdown,
+    frame_counter: f32,
+    line_texts: Vec<Entity>,
+    total_text: Option<Entity>,
+}
+
+impl ScoreTallyState {
+    pub fn new(level_nb: usize, next_level_nb: Option<usize>, breakdown: ScoreBreakdown) -> Self {
+        ScoreTallyState {
+            level_nb,
+            next_level_nb,
+            breakdown,
+            frame_counter: 0.,
+            line_texts: Vec::new(),
+            total_text: None,
+        }
+    }
+
+    fn tally_frames(&self) -> f32 {
+        self.breakdown.lines().len() as f32 * FRAMES_PER_LINE
+    }
+}
+
+impl SimpleState for ScoreTallyState {
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        *data.world.write_resource::<CurrentState>() = CurrentState::Tally;
+        let new_best = record_best_score(self.level_nb, self.breakdown.total());
+        let world = data.world;
+        world.write_resource::<Campaign>().total_score += self.breakdown.total();
+        initialise_camera(world);
+        let font = world.read_resource::<Loader>().load(
+            "fonts/pixel.ttf",
+            TtfFormat,
+            (),
+            &world.read_resource(),
+        );
+        add_text(
+            world,
+            font.clone(),
+            if new_best {
+                "New best score !".to_string()
+            } else {
+                "Level complete".to_string()
+            },
+            250.,
+            50.,
+        );
+        for (index, _) in self.breakdown.lines().iter().enumerate() {
+            let y = 150. - index as f32 * 45.;
+            self.line_texts
+                .push(add_text(world, font.clone(), String::new(), y, 30.));
+        }
+        self.total_text = Some(add_text(world, font, String::new(), -210., 40.));
+    }
+
+    fn fixed_update(&mut self, data: StateData<'_, GameData<'_, '_>>) -> SimpleTrans {
+        if self.frame_counter >= self.tally_frames() + FRAMES_AFTER_TALLY {
+            let mut pending = vec![(HighScoreTable::Level(self.level_nb), self.breakdown.total())];
+            let after = match self.next_level_nb {
+                Some(next_level_nb) => AfterEntry::NextLevel(next_level_nb),
+                None => {
+                    let campaign_score = data.world.read_resource::<Campaign>().total_score;
+                    pending.push((HighScoreTable::Campaign, campaign_score));
+                    AfterEntry::End
+                }
+            };
+            return enter_high_scores(pending, after);
+        }
+        self.frame_counter += 1.;
+
+        let mut ui_texts = data.world.write_storage::<UiText>();
+        let mut total = 0;
+        for (index, (label, points)) in self.breakdown.lines().into_iter().enumerate() {
+            let progress =
+                ((self.frame_counter - index as f32 * FRAMES_PER_LINE) / FRAMES_PER_LINE).max(0.);
+            if progress <= 0. {
+                break;
+            }
+            let shown = (points as f32 * progress.min(1.)) as u32;
+            total += shown;
+            if let Some(text) = ui_texts.get_mut(self.line_texts[index]) {
+                text.text = format!("{:<10} {:>6}", label, shown);
+            }
+        }
+        if let Some(entity) = self.total_text {
+            if let Some(text) = ui_texts.get_mut(entity) {
+                text.text = format!("Total {}", total);
+            }
+        }
+        Trans::None
+    }
+
+    fn on_stop(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        data.world.delete_all();
+        *data.world.write_resource::<CurrentState>() = CurrentState::NextLevel;
+    }
+}
+
+fn initialise_camera(world: &mut World) {
+    let mut transform = Transform::default();
+    transform.set_translation_xyz(0., 0., 1.1);
+    world
+        .create_entity()
+        .with(Camera::standard_2d(SCREEN_WIDTH, SCREEN_HEIGHT))
+        .with(transform)
+        .build();
+}
+
+fn add_text(
+    world: &mut World,
+    font: Handle<FontAsset>,
+    text: String,
+    y: f32,
+    font_size: f32,
+) -> Entity {
+    let transform = UiTransform::new(
+        "tally".to_string(),
+        Anchor::Middle,
+        Anchor::Middle,
+        0.,
+        y,
+        1.,
+        600.,
+        font_size + 10.,
+    );
+    world
+        .create_entity()
+        .with(transform)
+        .with(UiText::new(
+            font,
+            text,
+            [1., 1., 1., 1.],
+            font_size,
+            LineMode::Single,
+            Anchor::Middle,
+        ))
+        .build()
+}
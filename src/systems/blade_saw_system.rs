@@ -1,11 +1,15 @@
-use crate::entities::blade_saw::{BladeSaw, BladeSawSprite};
-use crate::entities::collision::are_colliding;
+use crate::entities::blade_saw::{BladeSaw, BladeSawHazard, BladeSawSprite};
+use crate::entities::collision::{are_colliding, Aabb};
 use crate::entities::ship::ShipParent;
+use crate::resources::events::{CollisionCause, ShipEvent};
 use crate::resources::main_resource::MainResource;
+use crate::resources::spatial_index::SpatialIndex;
 use crate::utils::sprites::sprite_to_entities::init_blade_saw_collider;
 use crate::utils::sprites::TILE_SIZE;
-use amethyst::core::ecs::{Join, Read, ReadStorage, System, Write, WriteStorage};
+use crate::utils::{distance_between_two_points, Point2D};
+use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
 use amethyst::core::num::FloatConst;
+use amethyst::core::shrev::EventChannel;
 use amethyst::core::{Time, Transform};
 
 pub struct BladeSawSystem;
@@ -14,24 +18,45 @@ impl<'s> System<'s> for BladeSawSystem {
     type SystemData = (
         WriteStorage<'s, BladeSaw>,
         ReadStorage<'s, BladeSawSprite>,
+        ReadStorage<'s, BladeSawHazard>,
         WriteStorage<'s, Transform>,
         Write<'s, MainResource>,
         ReadStorage<'s, ShipParent>,
         Read<'s, Time>,
+        Write<'s, EventChannel<ShipEvent>>,
+        Write<'s, SpatialIndex>,
+        Entities<'s>,
     );
 
     fn run(
         &mut self,
-        (mut blade_parents, blade_sprite, mut transforms, mut main_resource, ships, time): Self::SystemData,
+        (
+            mut blade_parents,
+            blade_sprite,
+            blade_hazards,
+            mut transforms,
+            mut main_resource,
+            ships,
+            time,
+            mut ship_events,
+            mut spatial_index,
+            entities,
+        ): Self::SystemData,
     ) {
         for (_, transform) in (&blade_sprite, &mut transforms).join() {
             transform.prepend_rotation_z_axis(3. * time.delta_seconds());
         }
         let mut ship_polygon = Vec::new();
+        let mut ship_center = (0., 0.);
         for (_ship, transform) in (&ships, &transforms).join() {
             ship_polygon = main_resource
                 .get_colliders_for_collision(transform.translation().x, transform.translation().y);
+            ship_center = (
+                transform.translation().x + 16.,
+                transform.translation().y - 16.,
+            );
         }
+        let ship_aabb = Aabb::from_polygons(&ship_polygon);
         for (blade, transform) in (&mut blade_parents, &mut transforms).join() {
             transform.append_translation_xyz(
                 50. * blade.direction_x * time.delta_seconds(),
@@ -49,10 +74,36 @@ impl<'s> System<'s> for BladeSawSystem {
             {
                 blade.direction_y *= -1.;
             }
-
+        }
+        for (_, transform, entity) in (&blade_hazards, &transforms, &entities).join() {
+            let (x, y) = (transform.translation().x, transform.translation().y);
+            spatial_index.update_area(entity, init_blade_saw_collider(x, y).aabb());
+        }
+        for entity in spatial_index.query(&ship_aabb) {
+            let transform = match (blade_hazards.get(entity), transforms.get(entity)) {
+                (Some(_), Some(transform)) => transform,
+                _ => continue,
+            };
+            let (x, y) = (transform.translation().x, transform.translation().y);
             let collider = init_blade_saw_collider(x, y);
-            if are_colliding(&ship_polygon, collider.polygons()) {
-                main_resource.ship_life -= main_resource.ship_life;
+            if ship_aabb.intersects(collider.aabb())
+                && are_colliding(&ship_polygon, collider.polygons())
+            {
+                let (saw_x, saw_y) = collider.aabb().center();
+                let distance =
+                    distance_between_two_points(saw_x, saw_y, ship_center.0, ship_center.1).max(1.);
+                ship_events.single_write(ShipEvent::Collided {
+                    cause: CollisionCause::BladeSaw,
+                    point: Point2D {
+                        x: ship_center.0,
+                        y: ship_center.1,
+                    },
+                    normal: (
+                        (ship_center.0 - saw_x) / distance,
+                        (ship_center.1 - saw_y) / distance,
+                    ),
+                    impact_speed: main_resource.x_force.hypot(main_resource.y_force),
+                });
             }
         }
     }
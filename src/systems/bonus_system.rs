@@ -1,14 +1,13 @@
 use crate::entities::bonus::{Bonus, BonusKind};
-use crate::entities::collision::are_colliding;
+use crate::entities::collision::{are_colliding, Aabb};
 use crate::entities::ship::ShipParent;
+use crate::resources::events::ShipEvent;
 use crate::resources::main_resource::MainResource;
-use crate::utils::sound::{play_bonus, Sounds};
+use crate::resources::spatial_index::SpatialIndex;
 use crate::utils::sprites::plasma_doors::EMPTY;
 use crate::utils::sprites::sprite_to_entities::init_bonus_collider;
-use amethyst::assets::AssetStorage;
-use amethyst::audio::output::Output;
-use amethyst::audio::Source;
-use amethyst::core::ecs::{Join, Read, ReadExpect, ReadStorage, System, Write, WriteStorage};
+use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+use amethyst::core::shrev::EventChannel;
 use amethyst::core::{Time, Transform};
 use amethyst::renderer::SpriteRender;
 
@@ -36,9 +35,9 @@ impl<'s> System<'s> for BonusSystem {
         ReadStorage<'s, ShipParent>,
         WriteStorage<'s, SpriteRender>,
         Read<'s, Time>,
-        Read<'s, AssetStorage<Source>>,
-        ReadExpect<'s, Sounds>,
-        Option<Read<'s, Output>>,
+        Write<'s, EventChannel<ShipEvent>>,
+        Entities<'s>,
+        Write<'s, SpatialIndex>,
     );
 
     fn run(
@@ -50,9 +49,9 @@ impl<'s> System<'s> for BonusSystem {
             ships,
             mut sprites,
             time,
-            storage,
-            sounds,
-            audio_output,
+            mut ship_events,
+            entities,
+            mut spatial_index,
         ): Self::SystemData,
     ) {
         self.change_direction_timer -= time.delta_seconds();
@@ -65,14 +64,47 @@ impl<'s> System<'s> for BonusSystem {
             ship_polygon = main_resource
                 .get_colliders_for_collision(transform.translation().x, transform.translation().y);
         }
-        for (bonus, transform, sprite) in (&mut bonuses, &mut transforms, &mut sprites).join() {
+        let ship_aabb = Aabb::from_polygons(&ship_polygon);
+        for (bonus, transform, sprite, entity) in
+            (&mut bonuses, &mut transforms, &mut sprites, &entities).join()
+        {
             transform.append_translation_xyz(0., self.direction_y * time.delta_seconds(), 0.);
             let (x, y) = (transform.translation().x, transform.translation().y);
-            let collider = init_bonus_collider(&bonus.kind, x, y);
+            spatial_index.update_area(entity, init_bonus_collider(&bonus.kind, x, y).aabb());
             if main_resource.should_reset_bonuses {
-                sprite.sprite_number = bonus.initial_sprite;
-                bonus.taken = false;
-            } else if !bonus.taken && are_colliding(&ship_polygon, collider.polygons()) {
+                let kept = main_resource
+                    .checkpoint
+                    .as_ref()
+                    .map(|checkpoint| checkpoint.taken_bonuses.contains(&entity))
+                    .unwrap_or(false);
+                if !kept {
+                    sprite.sprite_number = bonus.initial_sprite;
+                    bonus.taken = false;
+                }
+            }
+        }
+        if main_resource.should_reset_bonuses {
+            main_resource.should_reset_bonuses = false;
+            return;
+        }
+        for entity in spatial_index.query(&ship_aabb) {
+            let (bonus, transform, sprite) = match (
+                bonuses.get_mut(entity),
+                transforms.get(entity),
+                sprites.get_mut(entity),
+            ) {
+                (Some(bonus), Some(transform), Some(sprite)) => (bonus, transform, sprite),
+                _ => continue,
+            };
+            let collider = init_bonus_collider(
+                &bonus.kind,
+                transform.translation().x,
+                transform.translation().y,
+            );
+            if !bonus.taken
+                && ship_aabb.intersects(collider.aabb())
+                && are_colliding(&ship_polygon, collider.polygons())
+            {
                 match bonus.kind {
                     BonusKind::Fuel => {
                         main_resource.bonus_fuel();
@@ -84,11 +116,10 @@ impl<'s> System<'s> for BonusSystem {
                         main_resource.bonus_coin();
                     }
                 }
-                play_bonus(&*sounds, &storage, audio_output.as_deref());
+                ship_events.single_write(ShipEvent::BonusTaken { kind: bonus.kind });
                 bonus.taken = true;
                 sprite.sprite_number = EMPTY;
             }
         }
-        main_resource.should_reset_bonuses = false;
     }
 }
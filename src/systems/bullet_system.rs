@@ -1,12 +1,16 @@
 use crate::entities::canons::{
-    canon_kind_to_bullet_life_duration, canon_kind_to_bullet_speed, Bullet, Canon, CanonKind,
+    canon_kind_to_bullet_life_duration, Bullet, Canon, CanonKind, Turret,
 };
-use crate::entities::collision::{are_colliding, compute_is_eligible_for_collision, Colliders};
+use crate::entities::collision::{are_colliding, Aabb, Colliders, Transparent};
+use crate::entities::particle::ParticlePreset;
 use crate::entities::ship::ShipParent;
+use crate::resources::events::{CollisionCause, ShipEvent};
 use crate::resources::main_resource::MainResource;
-use crate::utils::sound::{play_air, play_hit, Sounds};
+use crate::resources::particles::ParticleBursts;
+use crate::resources::spatial_index::SpatialIndex;
+use crate::utils::sound::{play_air, Sounds};
 use crate::utils::sprites::sprite_to_entities::init_bullet_collider;
-use crate::utils::Direction;
+use crate::utils::Point2D;
 use amethyst::assets::AssetStorage;
 use amethyst::audio::output::Output;
 use amethyst::audio::Source;
@@ -14,6 +18,7 @@ use amethyst::core::ecs::{
     Entities, Join, Read, ReadExpect, ReadStorage, System, Write, WriteStorage,
 };
 use amethyst::core::math::Vector3;
+use amethyst::core::shrev::EventChannel;
 use amethyst::core::{Time, Transform};
 use geo::Polygon;
 
@@ -35,6 +40,8 @@ impl<'s> System<'s> for BulletSystem {
     type SystemData = (
         WriteStorage<'s, Bullet>,
         ReadStorage<'s, Canon>,
+        ReadStorage<'s, Turret>,
+        ReadStorage<'s, Transparent>,
         WriteStorage<'s, Transform>,
         ReadStorage<'s, Colliders>,
         ReadStorage<'s, ShipParent>,
@@ -44,6 +51,9 @@ impl<'s> System<'s> for BulletSystem {
         Read<'s, AssetStorage<Source>>,
         ReadExpect<'s, Sounds>,
         Option<Read<'s, Output>>,
+        Write<'s, SpatialIndex>,
+        Write<'s, EventChannel<ShipEvent>>,
+        Write<'s, ParticleBursts>,
     );
 
     fn run(
@@ -51,6 +61,8 @@ impl<'s> System<'s> for BulletSystem {
         (
             mut bullets,
             canons,
+            turrets,
+            transparents,
             mut transforms,
             colliders,
             ships,
@@ -60,6 +72,9 @@ impl<'s> System<'s> for BulletSystem {
             storage,
             sounds,
             audio_output,
+            mut spatial_index,
+            mut ship_events,
+            mut particle_bursts,
         ): Self::SystemData,
     ) {
         self.play_air_timer -= time.delta_seconds();
@@ -68,12 +83,14 @@ impl<'s> System<'s> for BulletSystem {
             ship_polygon = main_resource
                 .get_colliders_for_collision(transform.translation().x, transform.translation().y);
         }
+        let ship_aabb = Aabb::from_polygons(&ship_polygon);
         let mut bullet_vec: Vec<(u32, Colliders)> = Vec::new();
         for (bullet, transform, entity) in (&mut bullets, &mut transforms, &entities).join() {
             let colliders = init_bullet_collider(
                 &bullet.kind,
                 transform.translation().x,
                 transform.translation().y,
+                bullet.rotation,
             );
             match bullet.kind {
                 CanonKind::Air => {
@@ -87,71 +104,85 @@ impl<'s> System<'s> for BulletSystem {
                 }
                 _ => {}
             }
-            if are_colliding(colliders.polygons(), &ship_polygon) {
+            let mut hit_ship = false;
+            if colliders.aabb().intersects(&ship_aabb)
+                && are_colliding(colliders.polygons(), &ship_polygon)
+            {
                 match bullet.kind {
                     CanonKind::Air => {
                         if self.play_air_timer <= 0. {
                             play_air(&*sounds, &storage, audio_output.as_deref());
                             self.play_air_timer = DEFAULT_AIR_TIMER;
                         }
-                        match bullet.direction {
-                            Direction::Left => {
-                                main_resource.x_force -= 3. * time.delta_seconds();
-                            }
-                            Direction::Right => {
-                                main_resource.x_force += 3. * time.delta_seconds();
-                            }
-                            _ => {}
-                        }
+                        let push = 3. * time.delta_seconds() / bullet.speed;
+                        main_resource.x_force += bullet.velocity_x * push;
+                        main_resource.y_force += bullet.velocity_y * push;
                     }
                     _ => {
-                        play_hit(&*sounds, &storage, audio_output.as_deref());
-                        main_resource.bullet_hit();
-
-                        let _res = entities.delete(entity);
+                        let speed = bullet.velocity_x.hypot(bullet.velocity_y).max(1.);
+                        ship_events.single_write(ShipEvent::Collided {
+                            cause: CollisionCause::Bullet,
+                            point: Point2D {
+                                x: transform.translation().x,
+                                y: transform.translation().y,
+                            },
+                            normal: (bullet.velocity_x / speed, bullet.velocity_y / speed),
+                            impact_speed: speed,
+                        });
+                        hit_ship = true;
                     }
                 }
             } else {
                 bullet_vec.push((entity.id(), colliders));
             }
-            let bullet_speed = canon_kind_to_bullet_speed(&bullet.kind);
-            match bullet.direction {
-                Direction::Left => transform.append_translation_xyz(
-                    -1. * bullet_speed * time.delta_seconds(),
-                    0.,
-                    0.,
-                ),
-                Direction::Right => {
-                    transform.append_translation_xyz(bullet_speed * time.delta_seconds(), 0., 0.)
-                }
-                Direction::Top => {
-                    transform.append_translation_xyz(0., bullet_speed * time.delta_seconds(), 0.)
-                }
-                Direction::Bottom => transform.append_translation_xyz(
-                    0.,
-                    -1. * bullet_speed * time.delta_seconds(),
-                    0.,
-                ),
-                _ => transform.append_translation_xyz(0., 0., 0.),
-            };
+            transform.append_translation_xyz(
+                bullet.velocity_x * time.delta_seconds(),
+                bullet.velocity_y * time.delta_seconds(),
+                0.,
+            );
             bullet.life_duration -= time.delta_seconds();
-            if bullet.life_duration <= 0. {
+            if hit_ship || bullet.life_duration <= 0. {
+                spatial_index.remove(entity);
                 let _res = entities.delete(entity);
+            } else {
+                // Indexed where the bullet is drawn this frame, for the
+                // systems that check their own shapes against bullets.
+                let moved = init_bullet_collider(
+                    &bullet.kind,
+                    transform.translation().x,
+                    transform.translation().y,
+                    bullet.rotation,
+                );
+                spatial_index.update(entity, &moved);
             }
         }
 
-        let joined: Vec<_> = (&colliders, !&bullets, !&canons)
-            .join()
-            .filter(|(a, b, c)| {
-                return bullet_vec
-                    .iter()
-                    .any(|(id, collider)| compute_is_eligible_for_collision(*a, &collider));
-            })
-            .flat_map(|(a, b, c)| a.to_owned_polygons())
-            .collect();
         for (id, col) in bullet_vec.iter() {
-            if are_colliding(&col.to_owned_polygons(), &joined) {
+            let hit_structure = spatial_index
+                .query_colliders(col)
+                .into_iter()
+                .filter(|entity| {
+                    !bullets.contains(*entity)
+                        && !canons.contains(*entity)
+                        && !turrets.contains(*entity)
+                        && !transparents.contains(*entity)
+                })
+                .filter_map(|entity| colliders.get(entity))
+                .any(|collider| {
+                    collider.aabb().intersects(col.aabb())
+                        && are_colliding(col.polygons(), collider.polygons())
+                });
+            if hit_structure {
                 let e = entities.entity(*id);
+                if let (Some(bullet), Some(transform)) = (bullets.get(e), transforms.get(e)) {
+                    particle_bursts.push(
+                        ParticlePreset::Impact,
+                        transform.translation().x,
+                        transform.translation().y,
+                        (-bullet.velocity_y).atan2(-bullet.velocity_x),
+                    );
+                }
+                spatial_index.remove(e);
                 let _res = entities.delete(e);
             }
         }
@@ -0,0 +1,131 @@
+use crate::resources::camera_effects::{AccessibilitySettings, CameraEffects};
+use crate::resources::events::{CollisionCause, ShipEvent};
+use crate::resources::main_resource::MainResource;
+use amethyst::core::ecs::{Read, System, SystemData, World, Write};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+use amethyst::core::Time;
+use rand::Rng;
+
+const TRAUMA_DECAY: f32 = 1.2;
+const MAX_SHAKE: f32 = 12.;
+const DAMAGE_TRAUMA: f32 = 0.2;
+const DAMAGE_TRAUMA_PER_POINT: f32 = 0.15;
+const EXPLOSION_TRAUMA: f32 = 0.8;
+const HARD_LANDING_TRAUMA_PER_SPEED: f32 = 0.08;
+// Landings slower than this, in px per frame, do not shake the camera.
+const HARD_LANDING_SPEED: f32 = 1.;
+const EXPLOSION_ZOOM: f32 = -0.15;
+const ZOOM_PULSE_DECAY: f32 = 0.3;
+const MAX_SPEED_ZOOM: f32 = 0.25;
+const SPEED_FOR_MAX_ZOOM: f32 = 6.;
+const SPEED_ZOOM_EASING: f32 = 1.5;
+const HIT_STOP_DAMAGE: f32 = 0.06;
+const HIT_STOP_EXPLOSION: f32 = 0.12;
+const HIT_STOP_TIME_SCALE: f32 = 0.05;
+
+#[derive(Default)]
+pub struct CameraEffectsSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for CameraEffectsSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ShipEvent>>,
+        Write<'s, CameraEffects>,
+        Read<'s, AccessibilitySettings>,
+        Read<'s, MainResource>,
+        Write<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (ship_events, mut effects, settings, main_resource, mut time): Self::SystemData,
+    ) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            match event {
+                ShipEvent::Damaged {
+                    amount,
+                    remaining_life,
+                    ..
+                } => {
+                    effects.add_trauma(DAMAGE_TRAUMA + DAMAGE_TRAUMA_PER_POINT * *amount as f32);
+                    if *remaining_life > 0 {
+                        effects.hit_stop = HIT_STOP_DAMAGE;
+                    }
+                }
+                ShipEvent::Destroyed { .. } => {
+                    effects.add_trauma(EXPLOSION_TRAUMA);
+                    effects.zoom_pulse = EXPLOSION_ZOOM;
+                    effects.hit_stop = HIT_STOP_EXPLOSION;
+                }
+                ShipEvent::Collided {
+                    cause: CollisionCause::Platform,
+                    impact_speed,
+                    ..
+                } => {
+                    effects.add_trauma(impact_speed * HARD_LANDING_TRAUMA_PER_SPEED);
+                }
+                ShipEvent::Landed { .. } => {
+                    let (x, y) = main_resource.last_motion;
+                    let speed = x.abs().max(y.abs());
+                    if speed > HARD_LANDING_SPEED {
+                        effects.add_trauma(
+                            (speed - HARD_LANDING_SPEED) * HARD_LANDING_TRAUMA_PER_SPEED,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let delta = time.delta_real_seconds();
+        effects.trauma = (effects.trauma - TRAUMA_DECAY * delta).max(0.);
+        if !settings.screen_shake {
+            effects.trauma = 0.;
+        }
+        let shake = effects.trauma * effects.trauma * MAX_SHAKE;
+        let mut rng = rand::thread_rng();
+        effects.offset = (
+            rng.gen_range(-1., 1.) * shake,
+            rng.gen_range(-1., 1.) * shake,
+        );
+
+        if settings.camera_zoom {
+            let speed = main_resource.x_force.hypot(main_resource.y_force);
+            let target = 1. + (speed / SPEED_FOR_MAX_ZOOM).min(1.) * MAX_SPEED_ZOOM;
+            let blend = 1. - (-SPEED_ZOOM_EASING * delta).exp();
+            effects.speed_zoom += (target - effects.speed_zoom) * blend;
+            effects.zoom_pulse = if effects.zoom_pulse < 0. {
+                (effects.zoom_pulse + ZOOM_PULSE_DECAY * delta).min(0.)
+            } else {
+                0.
+            };
+        } else {
+            effects.speed_zoom = 1.;
+            effects.zoom_pulse = 0.;
+        }
+
+        if settings.hit_stop && effects.hit_stop > 0. {
+            effects.hit_stop -= delta;
+            time.set_time_scale(HIT_STOP_TIME_SCALE);
+        } else {
+            effects.hit_stop = 0.;
+            if time.time_scale() != 1. {
+                time.set_time_scale(1.);
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::entities::camera::CameraFollow;
+use crate::entities::ship::ShipParent;
+use crate::resources::camera_effects::CameraEffects;
+use crate::resources::main_resource::MainResource;
+use crate::utils::sprites::{SCREEN_HEIGHT, SCREEN_WIDTH, TILE_SIZE};
+use amethyst::core::ecs::{Join, Read, ReadStorage, System, WriteStorage};
+use amethyst::core::math::Vector3;
+use amethyst::core::{Time, Transform};
+
+pub struct CameraSystem;
+
+impl<'s> System<'s> for CameraSystem {
+    type SystemData = (
+        WriteStorage<'s, CameraFollow>,
+        ReadStorage<'s, ShipParent>,
+        WriteStorage<'s, Transform>,
+        Read<'s, MainResource>,
+        Read<'s, Time>,
+        Read<'s, CameraEffects>,
+    );
+
+    fn run(
+        &mut self,
+        (mut follows, ships, mut transforms, main_resource, time, effects): Self::SystemData,
+    ) {
+        let ship_position = match (&ships, &transforms).join().next() {
+            Some((_, transform)) => (transform.translation().x, transform.translation().y),
+            None => return,
+        };
+        let level = main_resource.level_config();
+        let level_width = level.width as f32 * TILE_SIZE;
+        let level_height = level.height as f32 * TILE_SIZE;
+
+        for (follow, transform) in (&mut follows, &mut transforms).join() {
+            let look_ahead = (
+                clamp_abs(
+                    main_resource.x_force * follow.look_ahead,
+                    follow.max_look_ahead,
+                ),
+                clamp_abs(
+                    main_resource.y_force * follow.look_ahead,
+                    follow.max_look_ahead,
+                ),
+            );
+            let target = (
+                ship_position.0 + look_ahead.0,
+                ship_position.1 + look_ahead.1,
+            );
+
+            let (x, y) = if let Some(camera) = follow.position {
+                let desired = (
+                    outside_deadzone(camera.0, target.0, follow.deadzone_x),
+                    outside_deadzone(camera.1, target.1, follow.deadzone_y),
+                );
+                let blend = 1. - (-follow.damping * time.delta_seconds()).exp();
+                (
+                    camera.0 + (desired.0 - camera.0) * blend,
+                    camera.1 + (desired.1 - camera.1) * blend,
+                )
+            } else {
+                target
+            };
+            let zoom = effects.zoom();
+            let (screen_width, screen_height) = (SCREEN_WIDTH * zoom, SCREEN_HEIGHT * zoom);
+            let position = (
+                clamp_to_level(x, level_width, screen_width),
+                clamp_to_level(y, level_height, screen_height),
+            );
+            follow.position = Some(position);
+
+            // Shaking must not show what lies beyond the level edges either.
+            transform.set_translation_x(clamp_to_level(
+                position.0 + effects.offset.0,
+                level_width,
+                screen_width,
+            ));
+            transform.set_translation_y(clamp_to_level(
+                position.1 + effects.offset.1,
+                level_height,
+                screen_height,
+            ));
+            transform.set_scale(Vector3::new(zoom, zoom, 1.));
+        }
+    }
+}
+
+fn clamp_abs(value: f32, max: f32) -> f32 {
+    value.max(-max).min(max)
+}
+
+/// Where the camera has to be for `target` to sit on the deadzone edge.
+fn outside_deadzone(camera: f32, target: f32, deadzone: f32) -> f32 {
+    let offset = target - camera;
+    if offset > deadzone {
+        target - deadzone
+    } else if offset < -deadzone {
+        target + deadzone
+    } else {
+        camera
+    }
+}
+
+/// Tiles are centred on multiples of `TILE_SIZE`, so the level spans from
+/// minus half a tile to its size minus half a tile.
+fn clamp_to_level(position: f32, level_size: f32, screen_size: f32) -> f32 {
+    let min = -TILE_SIZE / 2. + screen_size / 2.;
+    let max = level_size - TILE_SIZE / 2. - screen_size / 2.;
+    if min > max {
+        (level_size - TILE_SIZE) / 2.
+    } else {
+        position.max(min).min(max)
+    }
+}
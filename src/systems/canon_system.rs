@@ -1,9 +1,8 @@
-use crate::entities::canons::{
-    canon_kind_to_bullet_life_duration, canon_to_shooting_timer, Bullet, Canon, CanonKind,
-};
+use crate::entities::canons::{canon_to_shooting_timer, Bullet, Canon};
 use crate::entities::ship::ShipParent;
 use crate::resources::main_resource::MainResource;
-use crate::utils::{distance_between_two_points, Direction};
+use crate::utils::distance_between_two_points;
+use crate::utils::sprites::sprite_to_entities::bullet_sprite_and_rotation;
 use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, WriteStorage};
 use amethyst::core::{Time, Transform};
 use amethyst::renderer::SpriteRender;
@@ -57,86 +56,46 @@ impl<'s> System<'s> for CanonSystem {
                     canon.bullet_y_start,
                 ) < 300.
             {
-                match canon.kind {
-                    CanonKind::Air => {
-                        for bullet_index in 0..1 {
-                            let mut bullet_transform = Transform::default();
-                            bullet_transform.set_translation_xyz(
-                                canon.bullet_x_start - bullet_index as f32 * 15.,
-                                canon.bullet_y_start,
-                                0.9,
-                            );
-                            entities
-                                .build_entity()
-                                .with(
-                                    Bullet {
-                                        direction: canon.direction.clone(),
-                                        kind: canon.kind.clone(),
-                                        life_duration: canon_kind_to_bullet_life_duration(
-                                            &canon.kind,
-                                        ),
-                                    },
-                                    &mut bullets,
-                                )
-                                .with(
-                                    SpriteRender {
-                                        sprite_sheet: resource
-                                            .sprites
-                                            .as_ref()
-                                            .unwrap()
-                                            .bullet_sprite_render
-                                            .clone(),
-                                        sprite_number: {
-                                            match canon.direction {
-                                                Direction::Left => 2,
-                                                Direction::Right => 3,
-                                                _ => 0,
-                                            }
-                                        },
-                                    },
-                                    &mut sprite_renders,
-                                )
-                                .with(bullet_transform, &mut transforms)
-                                .build();
-                        }
-                    }
-                    _ => {
-                        let mut bullet_transform = Transform::default();
-                        bullet_transform.set_translation_xyz(
-                            canon.bullet_x_start,
-                            canon.bullet_y_start,
-                            0.9,
-                        );
-                        entities
-                            .build_entity()
-                            .with(
-                                Bullet {
-                                    direction: canon.direction.clone(),
-                                    kind: canon.kind.clone(),
-                                    life_duration: canon_kind_to_bullet_life_duration(&canon.kind),
-                                },
-                                &mut bullets,
-                            )
-                            .with(
-                                SpriteRender {
-                                    sprite_sheet: resource
-                                        .sprites
-                                        .as_ref()
-                                        .unwrap()
-                                        .bullet_sprite_render
-                                        .clone(),
-                                    sprite_number: 0,
-                                },
-                                &mut sprite_renders,
-                            )
-                            .with(bullet_transform, &mut transforms)
-                            .build();
-                    }
-                }
+                let (sprite_number, rotation) =
+                    bullet_sprite_and_rotation(&canon.kind, canon.angle);
+                let mut bullet_transform = Transform::default();
+                bullet_transform.set_translation_xyz(
+                    canon.bullet_x_start,
+                    canon.bullet_y_start,
+                    0.9,
+                );
+                bullet_transform.set_rotation_z_axis(rotation);
+                entities
+                    .build_entity()
+                    .with(
+                        Bullet::new(
+                            &canon.kind,
+                            canon.angle,
+                            resource.difficulty.bullet_speed_scale(),
+                        ),
+                        &mut bullets,
+                    )
+                    .with(
+                        SpriteRender {
+                            sprite_sheet: resource
+                                .sprites
+                                .as_ref()
+                                .unwrap()
+                                .bullet_sprite_render
+                                .clone(),
+                            sprite_number,
+                        },
+                        &mut sprite_renders,
+                    )
+                    .with(bullet_transform, &mut transforms)
+                    .build();
 
                 self.shooting_timers.remove(&entity.id());
-                self.shooting_timers
-                    .insert(entity.id(), canon_to_shooting_timer(&canon.kind));
+                self.shooting_timers.insert(
+                    entity.id(),
+                    canon_to_shooting_timer(&canon.kind)
+                        * resource.difficulty.shooting_timer_scale(),
+                );
             }
         }
     }
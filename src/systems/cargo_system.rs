@@ -0,0 +1,118 @@
+use crate::entities::cargo::{Cargo, CargoState};
+use crate::entities::collision::{LandingPlatform, PadKind};
+use crate::entities::ship::ShipParent;
+use crate::resources::events::ShipEvent;
+use crate::resources::main_resource::MainResource;
+use amethyst::core::ecs::{
+    Entities, Join, Read, ReadStorage, System, SystemData, World, Write, WriteStorage,
+};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+use amethyst::core::Transform;
+
+const PICKUP_RADIUS: f32 = 24.;
+const HANG_OFFSET: f32 = 24.;
+
+/// Picks up, carries and delivers cargo pods.
+#[derive(Default)]
+pub struct CargoSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for CargoSystem {
+    type SystemData = (
+        Entities<'s>,
+        WriteStorage<'s, Cargo>,
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, LandingPlatform>,
+        Write<'s, MainResource>,
+        Read<'s, EventChannel<ShipEvent>>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            mut cargos,
+            mut transforms,
+            ship_parents,
+            platforms,
+            mut main_resource,
+            ship_events,
+        ): Self::SystemData,
+    ) {
+        let ship_centre = match (&ship_parents, &transforms).join().next() {
+            Some((_, transform)) => (
+                transform.translation().x + 16.,
+                transform.translation().y - 16.,
+            ),
+            None => return,
+        };
+
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            if let ShipEvent::Landed { platform } = event {
+                let order = match platforms.get(*platform).map(|platform| platform.kind) {
+                    Some(PadKind::Delivery { order }) => order,
+                    _ => continue,
+                };
+                if let Some(carried) = main_resource.carried_cargo {
+                    if let Some(cargo) = cargos.get_mut(carried) {
+                        if cargo.order == order {
+                            cargo.state = CargoState::Delivered;
+                            main_resource.carried_cargo = None;
+                            main_resource.cargo_mass = 0.;
+                            main_resource.delivered_cargo += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        for (entity, cargo, transform) in (&entities, &mut cargos, &mut transforms).join() {
+            match cargo.state {
+                CargoState::Waiting => {
+                    let dx = transform.translation().x - ship_centre.0;
+                    let dy = transform.translation().y - ship_centre.1;
+                    if main_resource.carried_cargo.is_none()
+                        && !main_resource.is_exploding
+                        && cargo.order == main_resource.delivered_cargo
+                        && dx * dx + dy * dy < PICKUP_RADIUS * PICKUP_RADIUS
+                    {
+                        cargo.state = CargoState::Carried;
+                        main_resource.carried_cargo = Some(entity);
+                        // A tethered pod weighs on the ship through its rope instead.
+                        main_resource.cargo_mass = if cargo.tethered { 0. } else { cargo.mass };
+                    }
+                }
+                CargoState::Carried => {
+                    if main_resource.carried_cargo != Some(entity) {
+                        // Lost with the ship or destroyed, put it back where it was found.
+                        cargo.state = CargoState::Waiting;
+                        transform.set_translation_x(cargo.spawn_x);
+                        transform.set_translation_y(cargo.spawn_y);
+                        continue;
+                    }
+                    // Tethered pods are moved by the TetherSystem.
+                    if !cargo.tethered {
+                        transform.set_translation_x(ship_centre.0);
+                        transform.set_translation_y(ship_centre.1 - HANG_OFFSET);
+                    }
+                }
+                CargoState::Delivered => {}
+            }
+        }
+    }
+}
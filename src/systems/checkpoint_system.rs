@@ -0,0 +1,94 @@
+use crate::entities::bonus::Bonus;
+use crate::entities::collision::Checkpoint;
+use crate::entities::ship::ShipParent;
+use crate::resources::events::ShipEvent;
+use crate::resources::main_resource::{CheckpointSnapshot, MainResource};
+use amethyst::core::ecs::{
+    Entities, Join, Read, ReadStorage, System, SystemData, World, Write, WriteStorage,
+};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+use amethyst::core::{Parent, Transform};
+use amethyst::renderer::palette::Srgba;
+use amethyst::renderer::resources::Tint;
+
+const CHECKPOINT_REACHED_TINT: (f32, f32, f32) = (0.5, 1., 0.5);
+
+#[derive(Default)]
+pub struct CheckpointSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for CheckpointSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ShipEvent>>,
+        WriteStorage<'s, Checkpoint>,
+        WriteStorage<'s, Tint>,
+        ReadStorage<'s, Parent>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, Transform>,
+        ReadStorage<'s, Bonus>,
+        Write<'s, MainResource>,
+        Entities<'s>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (
+            ship_events,
+            mut checkpoints,
+            mut tints,
+            parents,
+            ships,
+            transforms,
+            bonuses,
+            mut main_resource,
+            entities,
+        ): Self::SystemData,
+    ) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            let platform = match event {
+                ShipEvent::Landed { platform } => *platform,
+                _ => continue,
+            };
+            let checkpoint = match checkpoints.get_mut(platform) {
+                Some(checkpoint) if !checkpoint.reached => checkpoint,
+                _ => continue,
+            };
+            checkpoint.reached = true;
+            let (r, g, b) = CHECKPOINT_REACHED_TINT;
+            if let Some(tint) = tints.get_mut(platform) {
+                tint.0 = Srgba::new(r, g, b, 1.);
+            }
+            for (parent, tint) in (&parents, &mut tints).join() {
+                if parent.entity == platform {
+                    tint.0 = Srgba::new(r, g, b, 1.);
+                }
+            }
+            for (_ship, transform) in (&ships, &transforms).join() {
+                main_resource.checkpoint = Some(CheckpointSnapshot {
+                    x: transform.translation().x,
+                    y: transform.translation().y,
+                    collected_coin: main_resource.collected_coin,
+                    taken_bonuses: (&bonuses, &entities)
+                        .join()
+                        .filter(|(bonus, _)| bonus.taken)
+                        .map(|(_, entity)| entity)
+                        .collect(),
+                });
+            }
+        }
+    }
+}
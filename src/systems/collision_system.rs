@@ -1,15 +1,27 @@
 use crate::entities::bonus::Bonus;
 use crate::entities::canons::Bullet;
 use crate::entities::collision::{
-    are_colliding, compute_is_eligible_for_collision, compute_ship_is_eligible_for_collision,
-    Colliders, LandingPlatform,
+    are_colliding, sweep, Aabb, Colliders, LandingPlatform, OneWay, Transparent,
 };
 use crate::entities::doors::{DoorState, PlasmaDoor};
 use crate::entities::ship::ShipParent;
+use crate::resources::events::{CollisionCause, ShipEvent};
 use crate::resources::main_resource::MainResource;
-use amethyst::core::ecs::{Join, ReadStorage, System, Write};
+use crate::resources::spatial_index::SpatialIndex;
+use crate::systems::ship_systems::ANGLE_ROTATION_DEGREE_MODIFIER;
+use crate::utils::sprites::TILE_SIZE;
+use crate::utils::Point2D;
+use amethyst::core::ecs::{Entity, Join, Read, ReadStorage, System, Write, WriteStorage};
+use amethyst::core::shrev::EventChannel;
 use amethyst::core::Transform;
 
+enum Obstacle {
+    Wall(CollisionCause),
+    Platform(Entity),
+}
+
+/// Sweeps the ship motion against walls and landing platforms at once, so
+/// whichever is met first decides between a crash and a landing.
 pub struct CollisionSystem;
 
 impl<'s> System<'s> for CollisionSystem {
@@ -19,9 +31,13 @@ impl<'s> System<'s> for CollisionSystem {
         ReadStorage<'s, PlasmaDoor>,
         ReadStorage<'s, Bonus>,
         ReadStorage<'s, ShipParent>,
-        ReadStorage<'s, Transform>,
+        WriteStorage<'s, Transform>,
         Write<'s, MainResource>,
         ReadStorage<'s, Bullet>,
+        ReadStorage<'s, Transparent>,
+        ReadStorage<'s, OneWay>,
+        Read<'s, SpatialIndex>,
+        Write<'s, EventChannel<ShipEvent>>,
     );
 
     fn run(
@@ -32,51 +48,169 @@ impl<'s> System<'s> for CollisionSystem {
             plasma_doors,
             bonus,
             ships,
-            transforms,
+            mut transforms,
             mut ship_resource,
             bullets,
+            transparents,
+            one_ways,
+            spatial_index,
+            mut ship_events,
         ): Self::SystemData,
     ) {
-        for (_ship, transform) in (&ships, &transforms).join() {
-            let ship_collider = ship_resource
-                .get_colliders_for_collision(transform.translation().x, transform.translation().y);
-            let (ship_x, ship_y) = (transform.translation().x, transform.translation().y);
-            for (collider, _, _, _, _) in (
-                &colliders,
-                !&landing_plateforms,
-                !&bullets,
-                !&plasma_doors,
-                !&bonus,
-            )
-                .join()
-            {
-                let struct_polygons = collider.polygons();
-                if !ship_resource.is_exploding
-                    && compute_ship_is_eligible_for_collision(
-                        &collider,
-                        ship_x,
-                        ship_x + 32.,
-                        ship_y - 32.,
-                        ship_y,
-                    )
-                    && are_colliding(&ship_collider, struct_polygons)
+        if ship_resource.is_exploding {
+            return;
+        }
+        for (_ship, transform) in (&ships, &mut transforms).join() {
+            let to = (transform.translation().x, transform.translation().y);
+            let from = (
+                to.0 - ship_resource.last_motion.0,
+                to.1 - ship_resource.last_motion.1,
+            );
+            let swept_aabb =
+                Aabb::from_polygons(&ship_resource.get_colliders_for_collision(from.0, from.1))
+                    .union(&Aabb::from_polygons(
+                        &ship_resource.get_colliders_for_collision(to.0, to.1),
+                    ))
+                    .union(&Aabb::from_polygons(
+                        &ship_resource.get_colliders_polygons_for_landing(from.0, from.1),
+                    ))
+                    .union(&Aabb::from_polygons(
+                        &ship_resource.get_colliders_polygons_for_landing(to.0, to.1),
+                    ));
+            let mut walls = Vec::new();
+            let mut platforms = Vec::new();
+            for entity in spatial_index.query(&swept_aabb) {
+                let collider = match colliders.get(entity) {
+                    Some(collider) => collider,
+                    None => continue,
+                };
+                if landing_plateforms.contains(entity) {
+                    // Resting on a platform is not a new contact.
+                    if !ship_resource.is_landed {
+                        platforms.push((collider, entity));
+                    }
+                    continue;
+                }
+                if bullets.contains(entity)
+                    || bonus.contains(entity)
+                    || transparents.contains(entity)
                 {
-                    ship_resource.ship_life -= ship_resource.ship_life;
+                    continue;
                 }
-            }
-            for (collider, door) in (&colliders, &plasma_doors).join() {
-                match door.state {
-                    DoorState::Closed => {
-                        let struct_polygons = collider.polygons();
-                        if !ship_resource.is_exploding
-                            && are_colliding(&ship_collider, struct_polygons)
-                        {
-                            ship_resource.ship_life -= ship_resource.ship_life;
-                        }
+                let mut cause = CollisionCause::Wall;
+                if let Some(door) = plasma_doors.get(entity) {
+                    if let DoorState::Open = door.state {
+                        continue;
                     }
-                    _ => {}
+                    cause = CollisionCause::PlasmaDoor;
+                }
+                if let Some(one_way) = one_ways.get(entity) {
+                    let angle = one_way.direction.to_angle();
+                    if ship_resource.x_force * angle.cos() + ship_resource.y_force * angle.sin()
+                        > 0.
+                    {
+                        continue;
+                    }
+                }
+                walls.push((collider, cause));
+            }
+            if walls.is_empty() && platforms.is_empty() {
+                continue;
+            }
+
+            let contact = sweep(from, to, |x, y| {
+                let ship_polygon = ship_resource.get_colliders_for_collision(x, y);
+                let ship_aabb = Aabb::from_polygons(&ship_polygon);
+                let wall = walls.iter().find(|(collider, _)| {
+                    ship_aabb.intersects(collider.aabb())
+                        && are_colliding(&ship_polygon, collider.polygons())
+                });
+                if let Some((collider, cause)) = wall {
+                    return Some((
+                        (Obstacle::Wall(*cause), *collider),
+                        meeting_point(&ship_aabb, collider),
+                    ));
+                }
+                let landing_polygon = ship_resource.get_colliders_polygons_for_landing(x, y);
+                let landing_aabb = Aabb::from_polygons(&landing_polygon);
+                platforms
+                    .iter()
+                    .find(|(collider, _)| {
+                        landing_aabb.intersects(collider.aabb())
+                            && are_colliding(&landing_polygon, collider.polygons())
+                            && (ship_resource.y_force < 0.
+                                || correct_landing_position(&ship_resource, x, collider))
+                    })
+                    .map(|(collider, entity)| {
+                        (
+                            (Obstacle::Platform(*entity), *collider),
+                            meeting_point(&landing_aabb, collider),
+                        )
+                    })
+            });
+            let contact = match contact {
+                Some(contact) => contact,
+                None => continue,
+            };
+            transform.set_translation_x(contact.x);
+            transform.set_translation_y(contact.y);
+            let (obstacle, collider) = contact.hit;
+            match obstacle {
+                Obstacle::Platform(entity)
+                    if correct_landing_position(&ship_resource, contact.x, collider) =>
+                {
+                    ship_resource.land_on(entity);
+                    ship_events.single_write(ShipEvent::Landed { platform: entity });
+                }
+                Obstacle::Platform(_) => {
+                    ship_events.single_write(ShipEvent::Collided {
+                        cause: CollisionCause::Platform,
+                        point: contact.point,
+                        normal: (0., 1.),
+                        impact_speed: (-ship_resource.y_force).max(0.),
+                    });
+                }
+                Obstacle::Wall(cause) => {
+                    let normal = collider
+                        .aabb()
+                        .normal_towards(contact.x + 16., contact.y - 16.);
+                    ship_events.single_write(ShipEvent::Collided {
+                        cause,
+                        point: contact.point,
+                        normal,
+                        // Only the speed into the wall counts, not a scrape along it.
+                        impact_speed: (-(ship_resource.x_force * normal.0
+                            + ship_resource.y_force * normal.1))
+                            .max(0.),
+                    });
                 }
             }
         }
     }
 }
+
+/// Centre of the overlap between the ship and what it hit.
+fn meeting_point(ship_aabb: &Aabb, collider: &Colliders) -> Point2D {
+    let (x, y) = ship_aabb.intersection(collider.aabb()).center();
+    Point2D { x, y }
+}
+
+fn correct_landing_position(
+    ship_resource: &MainResource,
+    ship_x: f32,
+    colliders: &Colliders,
+) -> bool {
+    let plateform_x_start = colliders.colliders().get(0).unwrap().top_left_point().x;
+    let plateform_x_end = colliders.colliders().get(0).unwrap().top_right_point().x;
+
+    let difficulty = ship_resource.difficulty;
+    let max_rotation = difficulty.max_landing_rotation_steps() * ANGLE_ROTATION_DEGREE_MODIFIER;
+    let overflow_tolerance = difficulty.landing_overflow_tolerance();
+
+    ship_resource.power == 0
+        && ship_resource.y_force > -difficulty.max_landing_speed()
+        && ship_resource.current_rotation_angle > -max_rotation
+        && ship_resource.current_rotation_angle < max_rotation
+        && ship_x + overflow_tolerance >= plateform_x_start
+        && (ship_x + TILE_SIZE - overflow_tolerance) <= plateform_x_end
+}
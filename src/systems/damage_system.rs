@@ -0,0 +1,103 @@
+use crate::entities::ship::ShipParent;
+use crate::resources::events::{CollisionCause, ShipEvent};
+use crate::resources::main_resource::{DamageModel, MainResource};
+use amethyst::core::ecs::{Join, ReadStorage, System, SystemData, World, Write, WriteStorage};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+use amethyst::core::Transform;
+
+const WEAR_PER_IMPACT_SPEED: f32 = 0.4;
+const LETHAL_IMPACT_SPEED: f32 = 3.5;
+// Distance the ship is moved off a wall before bouncing, so one scrape is
+// not reported again on the next frames.
+const SEPARATION: f32 = 1.;
+
+#[derive(Default)]
+pub struct DamageSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for DamageSystem {
+    type SystemData = (
+        Write<'s, MainResource>,
+        Write<'s, EventChannel<ShipEvent>>,
+        ReadStorage<'s, ShipParent>,
+        WriteStorage<'s, Transform>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (mut main_resource, mut ship_events, ships, mut transforms): Self::SystemData,
+    ) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        let collisions: Vec<(CollisionCause, (f32, f32), f32)> = ship_events
+            .read(reader)
+            .filter_map(|event| match event {
+                ShipEvent::Collided {
+                    cause,
+                    normal,
+                    impact_speed,
+                    ..
+                } => Some((*cause, *normal, *impact_speed)),
+                _ => None,
+            })
+            .collect();
+        for (cause, normal, impact_speed) in collisions {
+            if main_resource.is_exploding || main_resource.ship_life == 0 {
+                break;
+            }
+            let amount = match (cause, main_resource.damage_model) {
+                (CollisionCause::Bullet, _) => {
+                    main_resource.take_damage(1);
+                    1
+                }
+                (cause, DamageModel::Graduated { restitution })
+                    if cause == CollisionCause::Wall || cause == CollisionCause::Platform =>
+                {
+                    for (_, transform) in (&ships, &mut transforms).join() {
+                        transform.append_translation_xyz(
+                            normal.0 * SEPARATION,
+                            normal.1 * SEPARATION,
+                            0.,
+                        );
+                    }
+                    main_resource.bounce(normal, restitution);
+                    if impact_speed >= LETHAL_IMPACT_SPEED {
+                        let life = main_resource.ship_life;
+                        main_resource.take_damage(life);
+                        life
+                    } else {
+                        main_resource.wear_hull(impact_speed * WEAR_PER_IMPACT_SPEED)
+                    }
+                }
+                _ => {
+                    let life = main_resource.ship_life;
+                    main_resource.take_damage(life);
+                    life
+                }
+            };
+            if amount == 0 {
+                continue;
+            }
+            ship_events.single_write(ShipEvent::Damaged {
+                cause,
+                amount,
+                remaining_life: main_resource.ship_life,
+            });
+            if main_resource.ship_life == 0 {
+                ship_events.single_write(ShipEvent::Destroyed { cause });
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ use amethyst::renderer::SpriteRender;
 use std::collections::HashMap;
 
 const TIMING_CHANGE_SPRITE: f32 = 0.1;
+const DOOR_OPEN_DURATION: f32 = 2.0;
 
 pub struct PlasmaDoorSystem {
     sprite_changing_timer: f32,
@@ -59,7 +60,10 @@ impl<'s> System<'s> for PlasmaDoorSystem {
                     DoorState::Closed => {
                         door.state = DoorState::Open;
                         sprite.sprite_number = plasma_door_close_sprite(sprite.sprite_number);
-                        self.door_timers.insert(entity.id(), 2.0);
+                        self.door_timers.insert(
+                            entity.id(),
+                            DOOR_OPEN_DURATION * main_resource.difficulty.door_open_scale(),
+                        );
                     }
                     DoorState::Open => {
                         door.state = DoorState::Closed;
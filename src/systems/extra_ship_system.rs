@@ -0,0 +1,49 @@
+use crate::entities::bonus::BonusKind;
+use crate::resources::campaign::Campaign;
+use crate::resources::events::ShipEvent;
+use crate::resources::main_resource::MainResource;
+use amethyst::core::ecs::{Read, System, SystemData, World, Write};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+
+#[derive(Default)]
+pub struct ExtraShipSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for ExtraShipSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ShipEvent>>,
+        Write<'s, MainResource>,
+        Write<'s, Campaign>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(&mut self, (ship_events, mut main_resource, mut campaign): Self::SystemData) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            if let ShipEvent::BonusTaken {
+                kind: BonusKind::Coin,
+            } = event
+            {
+                if campaign.arcade
+                    && !main_resource.all_coins_awarded
+                    && main_resource.collected_coin == main_resource.level_config().coin_nb
+                {
+                    main_resource.all_coins_awarded = true;
+                    campaign.extra_ships += 1;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::entities::ship::ShipParent;
+use crate::entities::zone::{ForceZone, ZoneKind};
+use crate::resources::main_resource::MainResource;
+use crate::utils::distance_between_two_points;
+use amethyst::core::ecs::{Join, Read, ReadStorage, System, Write};
+use amethyst::core::{Time, Transform};
+use geo::contains::Contains;
+use geo::Point;
+
+pub struct ForceZoneSystem;
+
+impl<'s> System<'s> for ForceZoneSystem {
+    type SystemData = (
+        ReadStorage<'s, ForceZone>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, Transform>,
+        Write<'s, MainResource>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (zones, ships, transforms, mut main_resource, time): Self::SystemData) {
+        main_resource.gravity_scale = 1.;
+        if main_resource.is_landed || main_resource.is_exploding {
+            return;
+        }
+        for (_ship, transform) in (&ships, &transforms).join() {
+            let (ship_x, ship_y) = (
+                transform.translation().x + 16.,
+                transform.translation().y - 16.,
+            );
+            for zone in (&zones).join() {
+                if !zone.bounds.contains(ship_x, ship_y)
+                    || !zone.polygon.contains(&Point::new(ship_x, ship_y))
+                {
+                    continue;
+                }
+                match zone.kind {
+                    ZoneKind::Wind { x_force, y_force } => {
+                        main_resource.x_force += x_force * time.delta_seconds();
+                        main_resource.y_force += y_force * time.delta_seconds();
+                    }
+                    ZoneKind::GravityWell { strength, radius } => {
+                        let (center_x, center_y) = zone.bounds.center();
+                        let distance =
+                            distance_between_two_points(ship_x, ship_y, center_x, center_y);
+                        if distance > 1. && distance < radius {
+                            let pull = strength * (1. - distance / radius) * time.delta_seconds();
+                            main_resource.x_force += pull * (center_x - ship_x) / distance;
+                            main_resource.y_force += pull * (center_y - ship_y) / distance;
+                        }
+                    }
+                    ZoneKind::LowGravity { gravity } => {
+                        main_resource.gravity_scale = main_resource.gravity_scale.min(gravity);
+                    }
+                }
+            }
+        }
+    }
+}
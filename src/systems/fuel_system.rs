@@ -0,0 +1,147 @@
+use crate::entities::collision::LandingPlatform;
+use crate::entities::ship::{ShipParent, StrandedPrompt};
+use crate::resources::events::{CollisionCause, ShipEvent};
+use crate::resources::fuel::FuelStatus;
+use crate::resources::main_resource::MainResource;
+use crate::utils::sound::{play_low_fuel, Sounds};
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::ecs::{Join, Read, ReadExpect, ReadStorage, System, Write, WriteStorage};
+use amethyst::core::shrev::EventChannel;
+use amethyst::core::{Time, Transform};
+use amethyst::input::{InputHandler, StringBindings};
+use amethyst::ui::UiText;
+
+const LOW_FUEL_THRESHOLD: f32 = 100.;
+const WARNING_INTERVAL: f32 = 1.;
+const BLINK_INTERVAL: f32 = 0.25;
+const STRANDED_DELAY: f32 = 2.;
+// Below this speed, in px per frame, a drifting ship is considered stuck.
+const STALL_SPEED: f32 = 0.05;
+const SELF_DESTRUCT_COUNTDOWN: f32 = 5.;
+
+/// Warns when fuel runs low, and blows up a ship that ran dry once it can no
+/// longer reach fuel, so the player is never stuck in a level. Without fuel
+/// the ship can only fall, so it is stranded when it rests anywhere but on a
+/// refuelling pad, or when no such pad is left below it.
+#[derive(Default)]
+pub struct FuelSystem {
+    warning_timer: f32,
+    blink_timer: f32,
+}
+
+impl<'s> System<'s> for FuelSystem {
+    type SystemData = (
+        Write<'s, MainResource>,
+        Write<'s, FuelStatus>,
+        Write<'s, EventChannel<ShipEvent>>,
+        Read<'s, InputHandler<StringBindings>>,
+        Read<'s, Time>,
+        ReadStorage<'s, StrandedPrompt>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, LandingPlatform>,
+        ReadStorage<'s, Transform>,
+        WriteStorage<'s, UiText>,
+        Read<'s, AssetStorage<Source>>,
+        ReadExpect<'s, Sounds>,
+        Option<Read<'s, Output>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            mut main_resource,
+            mut status,
+            mut ship_events,
+            input,
+            time,
+            prompts,
+            ships,
+            platforms,
+            transforms,
+            mut ui_texts,
+            storage,
+            sounds,
+            audio_output,
+        ): Self::SystemData,
+    ) {
+        let delta = time.delta_seconds();
+        if main_resource.victory || main_resource.is_exploding || main_resource.should_be_reset {
+            *status = FuelStatus {
+                gauge_visible: true,
+                ..FuelStatus::default()
+            };
+        } else {
+            status.low = main_resource.ship_fuel < LOW_FUEL_THRESHOLD;
+            if main_resource.ship_fuel > 0. {
+                status.empty_timer = 0.;
+                status.self_destruct_countdown = None;
+            } else {
+                let stalled = main_resource.x_force.hypot(main_resource.y_force) < STALL_SPEED;
+                if !status.refuelling && (main_resource.is_landed || stalled) {
+                    status.empty_timer += delta;
+                } else {
+                    status.empty_timer = 0.;
+                }
+                let ship_y = (&ships, &transforms)
+                    .join()
+                    .next()
+                    .map(|(_, transform)| transform.translation().y);
+                let level = main_resource.level_config();
+                let refuel_pad_below = (&platforms, &transforms).join().any(|(platform, pad)| {
+                    level.refuel_rate(platform.kind).is_some()
+                        && ship_y.map_or(true, |y| pad.translation().y < y)
+                });
+                let stranded = status.empty_timer >= STRANDED_DELAY
+                    || (!main_resource.is_landed && !refuel_pad_below);
+                if stranded && status.self_destruct_countdown.is_none() {
+                    status.self_destruct_countdown = Some(SELF_DESTRUCT_COUNTDOWN);
+                }
+            }
+
+            if let Some(countdown) = status.self_destruct_countdown {
+                let countdown = countdown - delta;
+                status.self_destruct_countdown = Some(countdown);
+                if countdown <= 0. || input.action_is_down("self_destruct") == Some(true) {
+                    main_resource.ship_life = 0;
+                    ship_events.single_write(ShipEvent::Destroyed {
+                        cause: CollisionCause::SelfDestruct,
+                    });
+                    status.self_destruct_countdown = None;
+                }
+            }
+
+            self.warning_timer -= delta;
+            self.blink_timer -= delta;
+            if status.refuelling {
+                status.gauge_visible = true;
+                if self.blink_timer <= 0. {
+                    status.fill_indicator = !status.fill_indicator;
+                    self.blink_timer = BLINK_INTERVAL;
+                }
+            } else if status.low {
+                if self.warning_timer <= 0. && status.self_destruct_countdown.is_none() {
+                    play_low_fuel(&*sounds, &storage, audio_output.as_deref());
+                    self.warning_timer = WARNING_INTERVAL;
+                }
+                if self.blink_timer <= 0. {
+                    status.gauge_visible = !status.gauge_visible;
+                    self.blink_timer = BLINK_INTERVAL;
+                }
+            } else {
+                status.gauge_visible = true;
+            }
+        }
+
+        for (_, text) in (&prompts, &mut ui_texts).join() {
+            text.text = match status.self_destruct_countdown {
+                Some(countdown) => format!(
+                    "Out of fuel! Self-destruct in {} (R)",
+                    countdown.ceil().max(0.)
+                ),
+                None => String::new(),
+            };
+        }
+    }
+}
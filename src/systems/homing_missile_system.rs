@@ -0,0 +1,47 @@
+use crate::entities::canons::{Bullet, HomingMissile};
+use crate::entities::ship::ShipParent;
+use crate::utils::{angle_between_two_points, rotate_towards};
+use amethyst::core::ecs::{Join, Read, ReadStorage, System, WriteStorage};
+use amethyst::core::{Time, Transform};
+
+pub struct HomingMissileSystem;
+
+impl<'s> System<'s> for HomingMissileSystem {
+    type SystemData = (
+        WriteStorage<'s, Bullet>,
+        WriteStorage<'s, HomingMissile>,
+        ReadStorage<'s, ShipParent>,
+        WriteStorage<'s, Transform>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (mut bullets, mut missiles, ships, mut transforms, time): Self::SystemData) {
+        let ship_center = (&ships, &transforms)
+            .join()
+            .map(|(_, transform)| {
+                (
+                    transform.translation().x + 16.,
+                    transform.translation().y - 16.,
+                )
+            })
+            .last();
+        let (ship_x, ship_y) = match ship_center {
+            Some(center) => center,
+            None => return,
+        };
+        for (bullet, missile, transform) in (&mut bullets, &mut missiles, &mut transforms).join() {
+            if missile.fuel <= 0. {
+                continue;
+            }
+            missile.fuel -= time.delta_seconds();
+            let (x, y) = (transform.translation().x, transform.translation().y);
+            let angle = rotate_towards(
+                bullet.angle(),
+                angle_between_two_points(x, y, ship_x, ship_y),
+                missile.turn_rate * time.delta_seconds(),
+            );
+            bullet.steer(angle);
+            transform.set_rotation_z_axis(angle);
+        }
+    }
+}
@@ -1,13 +1,35 @@
 pub mod blade_saw_system;
 pub mod bonus_system;
 pub mod bullet_system;
+pub mod camera_effects_system;
+pub mod camera_system;
 pub mod canon_system;
+pub mod cargo_system;
+pub mod checkpoint_system;
 pub mod collision_system;
+pub mod damage_system;
 pub mod doors;
 pub mod explosion_systems;
-pub mod landing_system;
+pub mod extra_ship_system;
+pub mod force_zone_system;
+pub mod fuel_system;
+pub mod homing_missile_system;
 pub mod menu_background_system;
+pub mod moving_platform_system;
+pub mod objectives_system;
+pub mod pad_system;
+pub mod particle_effects_system;
+pub mod particle_system;
+pub mod path_system;
 pub mod score_system;
+pub mod secret_passage_system;
+pub mod ship_effects_system;
+pub mod ship_sound_system;
 pub mod ship_systems;
+pub mod stats_system;
+pub mod target_system;
+pub mod tether_system;
 pub mod thruster_system;
+pub mod turret_system;
 pub mod ui_system;
+pub mod zone_particle_system;
@@ -0,0 +1,48 @@
+use crate::entities::collision::{Colliders, MovingPlatform};
+use crate::resources::main_resource::MainResource;
+use crate::resources::spatial_index::SpatialIndex;
+use crate::utils::sprites::sprite_to_entities::init_pad_collider;
+use amethyst::core::ecs::{Entities, Join, ReadStorage, System, Write, WriteStorage};
+use amethyst::core::Transform;
+
+pub struct MovingPlatformSystem;
+
+impl<'s> System<'s> for MovingPlatformSystem {
+    type SystemData = (
+        WriteStorage<'s, MovingPlatform>,
+        WriteStorage<'s, Colliders>,
+        ReadStorage<'s, Transform>,
+        Write<'s, MainResource>,
+        Entities<'s>,
+        Write<'s, SpatialIndex>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            mut platforms,
+            mut colliders,
+            transforms,
+            mut main_resource,
+            entities,
+            mut spatial_index,
+        ): Self::SystemData,
+    ) {
+        main_resource.platform_velocity = (0., 0.);
+        for (platform, collider, transform, entity) in
+            (&mut platforms, &mut colliders, &transforms, &entities).join()
+        {
+            let (x, y) = (transform.translation().x, transform.translation().y);
+            platform.velocity_x = x - platform.last_x;
+            platform.velocity_y = y - platform.last_y;
+            platform.last_x = x;
+            platform.last_y = y;
+            *collider = init_pad_collider(x, y);
+            spatial_index.update(entity, collider);
+
+            if main_resource.is_landed && main_resource.landed_platform == Some(entity) {
+                main_resource.platform_velocity = (platform.velocity_x, platform.velocity_y);
+            }
+        }
+    }
+}
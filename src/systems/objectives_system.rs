@@ -0,0 +1,127 @@
+use crate::entities::collision::{Arrival, Waypoint};
+use crate::resources::events::{CollisionCause, ShipEvent};
+use crate::resources::main_resource::MainResource;
+use crate::resources::objectives::{Objective, ObjectiveStatus, Objectives};
+use amethyst::core::ecs::{Read, ReadStorage, System, SystemData, World, Write};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+use amethyst::core::Time;
+
+/// Tracks the level's objectives. The `ScoreSystem` only accepts a landing
+/// on the arrival once they are completed.
+#[derive(Default)]
+pub struct ObjectivesSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for ObjectivesSystem {
+    type SystemData = (
+        ReadStorage<'s, Waypoint>,
+        ReadStorage<'s, Arrival>,
+        Write<'s, Objectives>,
+        Write<'s, MainResource>,
+        Write<'s, EventChannel<ShipEvent>>,
+        Read<'s, Time>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(
+        &mut self,
+        (waypoints, arrivals, mut objectives, mut main_resource, mut ship_events, time): Self::SystemData,
+    ) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            match event {
+                ShipEvent::Landed { platform } => {
+                    if arrivals.contains(*platform) {
+                        let (x, y) = main_resource.last_motion;
+                        // Same unit as the speed shown on the HUD.
+                        objectives.last_landing_speed = Some(x.abs().max(y.abs()) * 10.);
+                    }
+                    if let Some(waypoint) = waypoints.get(*platform) {
+                        if waypoint.order == objectives.next_waypoint {
+                            objectives.next_waypoint += 1;
+                        }
+                    }
+                }
+                ShipEvent::Destroyed { .. } => {
+                    objectives.life_time = 0.;
+                }
+                _ => {}
+            }
+        }
+
+        if !main_resource.is_landed {
+            objectives.last_landing_speed = None;
+        }
+        if !main_resource.is_exploding && !main_resource.victory {
+            objectives.life_time += time.delta_seconds();
+        }
+
+        let mut out_of_time = false;
+        for i in 0..objectives.entries.len() {
+            let (objective, status) = objectives.entries[i];
+            let status = match objective {
+                Objective::CollectCoins(count) => {
+                    let count = count.unwrap_or(main_resource.level_config().coin_nb);
+                    done_if(main_resource.collected_coin >= count)
+                }
+                Objective::DeliverCargo => done_if(
+                    main_resource.delivered_cargo >= main_resource.level_config().cargos.len(),
+                ),
+                Objective::VisitPads => done_if(
+                    objectives.next_waypoint >= main_resource.level_config().waypoint_count(),
+                ),
+                Objective::DestroyTargets => done_if(
+                    objectives.targets_destroyed >= main_resource.level_config().targets.len(),
+                ),
+                Objective::Survive(_) if status == ObjectiveStatus::Done => status,
+                Objective::Survive(seconds) => done_if(objectives.life_time >= seconds),
+                Objective::SoftLanding(speed) => match objectives.last_landing_speed {
+                    Some(landing_speed) if landing_speed <= speed => ObjectiveStatus::Done,
+                    Some(_) => ObjectiveStatus::Failed,
+                    None => ObjectiveStatus::Pending,
+                },
+                Objective::FinishWithin(_) if main_resource.victory => status,
+                Objective::FinishWithin(seconds) if objectives.life_time > seconds => {
+                    out_of_time = true;
+                    ObjectiveStatus::Failed
+                }
+                Objective::FinishWithin(_) => ObjectiveStatus::Pending,
+            };
+            objectives.entries[i].1 = status;
+        }
+
+        if main_resource.victory {
+            for (objective, status) in objectives.entries.iter_mut() {
+                if let Objective::FinishWithin(_) = objective {
+                    *status = ObjectiveStatus::Done;
+                }
+            }
+        } else if out_of_time && !main_resource.is_exploding {
+            main_resource.ship_life = 0;
+            ship_events.single_write(ShipEvent::Destroyed {
+                cause: CollisionCause::OutOfTime,
+            });
+            objectives.life_time = 0.;
+        }
+    }
+}
+
+fn done_if(condition: bool) -> ObjectiveStatus {
+    if condition {
+        ObjectiveStatus::Done
+    } else {
+        ObjectiveStatus::Pending
+    }
+}
@@ -0,0 +1,82 @@
+use crate::entities::collision::{LandingPlatform, PadKind};
+use crate::resources::fuel::FuelStatus;
+use crate::resources::main_resource::MainResource;
+use crate::utils::sound::{play_bonus, play_refuel, Sounds};
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::ecs::{Read, ReadExpect, ReadStorage, System, Write};
+use amethyst::core::Time;
+
+const REPAIR_INTERVAL: f32 = 1.;
+const REFUEL_SOUND_INTERVAL: f32 = 0.5;
+
+/// Applies the behaviour of the pad the ship is resting on.
+#[derive(Default)]
+pub struct PadSystem {
+    repair_timer: f32,
+    refuel_sound_timer: f32,
+}
+
+impl<'s> System<'s> for PadSystem {
+    type SystemData = (
+        ReadStorage<'s, LandingPlatform>,
+        Write<'s, MainResource>,
+        Write<'s, FuelStatus>,
+        Read<'s, Time>,
+        Read<'s, AssetStorage<Source>>,
+        ReadExpect<'s, Sounds>,
+        Option<Read<'s, Output>>,
+    );
+
+    fn run(
+        &mut self,
+        (platforms, mut main_resource, mut fuel_status, time, storage, sounds, audio_output): Self::SystemData,
+    ) {
+        let delta = time.delta_seconds();
+        let kind = if main_resource.is_landed && !main_resource.is_exploding {
+            main_resource
+                .landed_platform
+                .and_then(|platform| platforms.get(platform))
+                .map(|platform| platform.kind)
+                .map(
+                    |kind| match main_resource.level_config().refuel_rate(kind) {
+                        Some(rate) => PadKind::Refuel { rate },
+                        None => kind,
+                    },
+                )
+        } else {
+            None
+        };
+
+        fuel_status.refuelling = false;
+        match kind {
+            Some(PadKind::Refuel { rate })
+                if main_resource.ship_fuel < main_resource.difficulty.fuel_capacity() =>
+            {
+                main_resource.fuel_up(rate, delta);
+                fuel_status.refuelling = true;
+                self.refuel_sound_timer -= delta;
+                if self.refuel_sound_timer <= 0. {
+                    play_refuel(&*sounds, &storage, audio_output.as_deref());
+                    self.refuel_sound_timer = REFUEL_SOUND_INTERVAL;
+                }
+            }
+            Some(PadKind::Repair)
+                if main_resource.ship_life < main_resource.difficulty.ship_life() =>
+            {
+                self.repair_timer -= delta;
+                if self.repair_timer <= 0. {
+                    main_resource.bonus_heal();
+                    main_resource.hull_wear = 0.;
+                    play_bonus(&*sounds, &storage, audio_output.as_deref());
+                    self.repair_timer = REPAIR_INTERVAL;
+                }
+            }
+            _ => {
+                self.repair_timer = REPAIR_INTERVAL;
+                self.refuel_sound_timer = 0.;
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::entities::particle::ParticlePreset;
+use crate::entities::ship::ShipParent;
+use crate::resources::events::{CollisionCause, ShipEvent};
+use crate::resources::particles::ParticleBursts;
+use amethyst::core::ecs::{Join, Read, ReadStorage, System, SystemData, World, Write};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+use amethyst::core::Transform;
+use std::f32::consts::FRAC_PI_2;
+
+#[derive(Default)]
+pub struct ParticleEffectsSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for ParticleEffectsSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ShipEvent>>,
+        Write<'s, ParticleBursts>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, Transform>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(&mut self, (ship_events, mut bursts, ships, transforms): Self::SystemData) {
+        let ship_center = (&ships, &transforms)
+            .join()
+            .next()
+            .map(|(_, transform)| {
+                (
+                    transform.translation().x + 16.,
+                    transform.translation().y - 16.,
+                )
+            })
+            .unwrap_or((0., 0.));
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            match event {
+                ShipEvent::Collided {
+                    cause: CollisionCause::Bullet,
+                    point,
+                    normal,
+                    ..
+                } => {
+                    let direction = (-normal.1).atan2(-normal.0);
+                    bursts.push(ParticlePreset::Impact, point.x, point.y, direction);
+                }
+                ShipEvent::Collided { point, normal, .. } => {
+                    let direction = normal.1.atan2(normal.0);
+                    bursts.push(ParticlePreset::Sparks, point.x, point.y, direction);
+                }
+                ShipEvent::Destroyed { .. } => {
+                    bursts.push(
+                        ParticlePreset::Debris,
+                        ship_center.0,
+                        ship_center.1,
+                        FRAC_PI_2,
+                    );
+                }
+                ShipEvent::BonusTaken { .. } => {
+                    bursts.push(
+                        ParticlePreset::Sparkles,
+                        ship_center.0,
+                        ship_center.1,
+                        FRAC_PI_2,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::entities::particle::{Particle, ParticleEmitter, ParticlePreset, PARTICLE_SPRITE};
+use crate::resources::main_resource::MainResource;
+use crate::resources::particles::ParticleBursts;
+use amethyst::core::ecs::{Entities, Join, Read, System, Write, WriteStorage};
+use amethyst::core::math::Vector3;
+use amethyst::core::{Time, Transform};
+use amethyst::renderer::palette::Srgba;
+use amethyst::renderer::resources::Tint;
+use amethyst::renderer::transparent::Transparent;
+use amethyst::renderer::SpriteRender;
+use rand::Rng;
+
+const MAX_PARTICLES: usize = 400;
+const PARTICLE_Z: f32 = 0.85;
+
+pub struct ParticleSystem;
+
+impl<'s> System<'s> for ParticleSystem {
+    type SystemData = (
+        WriteStorage<'s, Particle>,
+        WriteStorage<'s, ParticleEmitter>,
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, SpriteRender>,
+        WriteStorage<'s, Tint>,
+        WriteStorage<'s, Transparent>,
+        Write<'s, ParticleBursts>,
+        Read<'s, MainResource>,
+        Read<'s, Time>,
+        Entities<'s>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            mut particles,
+            mut emitters,
+            mut transforms,
+            mut sprites,
+            mut tints,
+            mut transparents,
+            mut bursts,
+            main_resource,
+            time,
+            entities,
+        ): Self::SystemData,
+    ) {
+        let delta = time.delta_seconds();
+        let mut alive = 0;
+        for (particle, transform, tint, entity) in
+            (&mut particles, &mut transforms, &mut tints, &entities).join()
+        {
+            particle.life -= delta;
+            if particle.life <= 0. {
+                let _res = entities.delete(entity);
+                continue;
+            }
+            alive += 1;
+            let config = particle.preset.config();
+            particle.velocity_y -= config.gravity * delta;
+            transform.append_translation_xyz(
+                particle.velocity_x * delta,
+                particle.velocity_y * delta,
+                0.,
+            );
+            let age = 1. - particle.life / particle.max_life;
+            let color = lerp_color(config.start_color, config.end_color, age);
+            tint.0 = Srgba::new(color[0], color[1], color[2], color[3]);
+            let scale = config.start_scale + (config.end_scale - config.start_scale) * age;
+            transform.set_scale(Vector3::new(scale, scale, 1.));
+        }
+
+        let mut spawns: Vec<(ParticlePreset, f32, f32, f32)> = Vec::new();
+        for (emitter, transform) in (&mut emitters, &transforms).join() {
+            emitter.accumulator += emitter.rate * delta;
+            if emitter.accumulator < 1. {
+                continue;
+            }
+            let matrix = transform.global_matrix();
+            let (x, y) = (matrix[(0, 3)], matrix[(1, 3)]);
+            let rotation = matrix[(1, 0)].atan2(matrix[(0, 0)]);
+            while emitter.accumulator >= 1. {
+                emitter.accumulator -= 1.;
+                spawns.push((emitter.preset, x, y, rotation + emitter.direction));
+            }
+        }
+        for burst in bursts.drain() {
+            for _ in 0..burst.preset.config().burst {
+                spawns.push((burst.preset, burst.x, burst.y, burst.direction));
+            }
+        }
+
+        let sprite_sheet = match main_resource.sprites.as_ref() {
+            Some(sprites) => sprites.bullet_sprite_render.clone(),
+            None => return,
+        };
+        let mut rng = rand::thread_rng();
+        for (preset, x, y, direction) in spawns
+            .into_iter()
+            .take(MAX_PARTICLES - alive.min(MAX_PARTICLES))
+        {
+            let config = preset.config();
+            let angle = direction + rng.gen_range(-config.spread, config.spread);
+            let speed = rng.gen_range(config.speed.0, config.speed.1);
+            let life = rng.gen_range(config.lifetime.0, config.lifetime.1);
+            let mut transform = Transform::default();
+            transform.set_translation_xyz(x, y, PARTICLE_Z);
+            transform.set_scale(Vector3::new(config.start_scale, config.start_scale, 1.));
+            let color = config.start_color;
+            entities
+                .build_entity()
+                .with(
+                    Particle {
+                        velocity_x: speed * angle.cos(),
+                        velocity_y: speed * angle.sin(),
+                        life,
+                        max_life: life,
+                        preset,
+                    },
+                    &mut particles,
+                )
+                .with(transform, &mut transforms)
+                .with(
+                    SpriteRender {
+                        sprite_sheet: sprite_sheet.clone(),
+                        sprite_number: PARTICLE_SPRITE,
+                    },
+                    &mut sprites,
+                )
+                .with(
+                    Tint(Srgba::new(color[0], color[1], color[2], color[3])),
+                    &mut tints,
+                )
+                .with(Transparent, &mut transparents)
+                .build();
+        }
+    }
+}
+
+fn lerp_color(from: [f32; 4], to: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        from[0] + (to[0] - from[0]) * t,
+        from[1] + (to[1] - from[1]) * t,
+        from[2] + (to[2] - from[2]) * t,
+        from[3] + (to[3] - from[3]) * t,
+    ]
+}
@@ -0,0 +1,40 @@
+use crate::entities::canons::Turret;
+use crate::entities::collision::Colliders;
+use crate::entities::path::PathFollower;
+use crate::resources::spatial_index::SpatialIndex;
+use crate::utils::sprites::sprite_to_entities::init_mounted_canon_collider;
+use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+use amethyst::core::{Time, Transform};
+
+pub struct PathFollowerSystem;
+
+impl<'s> System<'s> for PathFollowerSystem {
+    type SystemData = (
+        WriteStorage<'s, PathFollower>,
+        WriteStorage<'s, Transform>,
+        ReadStorage<'s, Turret>,
+        WriteStorage<'s, Colliders>,
+        Write<'s, SpatialIndex>,
+        Entities<'s>,
+        Read<'s, Time>,
+    );
+
+    fn run(
+        &mut self,
+        (mut paths, mut transforms, turrets, mut colliders, mut spatial_index, entities, time): Self::SystemData,
+    ) {
+        for (path, transform) in (&mut paths, &mut transforms).join() {
+            let (x, y) = path.advance(time.delta_seconds());
+            transform.set_translation_x(x);
+            transform.set_translation_y(y);
+        }
+        // Patrol drones carry their turret body along.
+        for (_, _, transform, collider, entity) in
+            (&paths, &turrets, &transforms, &mut colliders, &entities).join()
+        {
+            *collider =
+                init_mounted_canon_collider(transform.translation().x, transform.translation().y);
+            spatial_index.update(entity, collider);
+        }
+    }
+}
@@ -1,8 +1,18 @@
-use crate::entities::collision::{are_colliding, Arrival, Colliders};
+use crate::entities::bonus::BonusKind;
+use crate::entities::collision::{Arrival, Colliders};
 use crate::entities::ship::ShipParent;
 use crate::entities::TransitionFade;
+use crate::resources::events::ShipEvent;
+use crate::resources::leaderboard::Leaderboard;
 use crate::resources::main_resource::MainResource;
-use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, Write, WriteStorage};
+use crate::resources::objectives::Objectives;
+use crate::resources::score::{ScoreBreakdown, ScoreTracker};
+use crate::resources::stats::ShipStats;
+use crate::utils::leaderboard::LeaderboardRun;
+use amethyst::core::ecs::{
+    Entities, Join, Read, ReadExpect, ReadStorage, System, SystemData, World, Write, WriteStorage,
+};
+use amethyst::core::shrev::{EventChannel, ReaderId};
 use amethyst::core::{Time, Transform};
 use amethyst::renderer::palette::Srgba;
 use amethyst::ui::{Anchor, ScaleMode, UiImage, UiImagePrefab, UiTransform, UiWidget};
@@ -11,12 +21,14 @@ const DEFAULT_TIMER: f32 = 0.6;
 
 pub struct ScoreSystem {
     fade_in_timer: f32,
+    reader: Option<ReaderId<ShipEvent>>,
 }
 
 impl Default for ScoreSystem {
     fn default() -> Self {
         ScoreSystem {
             fade_in_timer: DEFAULT_TIMER,
+            reader: None,
         }
     }
 }
@@ -33,8 +45,22 @@ impl<'s> System<'s> for ScoreSystem {
         WriteStorage<'s, UiTransform>,
         WriteStorage<'s, UiImage>,
         WriteStorage<'s, TransitionFade>,
+        Read<'s, EventChannel<ShipEvent>>,
+        Write<'s, ScoreTracker>,
+        Read<'s, ShipStats>,
+        ReadExpect<'s, Leaderboard>,
+        Read<'s, Objectives>,
     );
 
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
     fn run(
         &mut self,
         (
@@ -48,21 +74,54 @@ impl<'s> System<'s> for ScoreSystem {
             mut ui_transforms,
             mut images,
             mut fades,
+            ship_events,
+            mut score,
+            stats,
+            leaderboard,
+            objectives,
         ): Self::SystemData,
     ) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            if let ShipEvent::BonusTaken {
+                kind: BonusKind::Coin,
+            } = event
+            {
+                score.coin_taken();
+            }
+        }
+        if !main_resource.victory {
+            score.tick(time.delta_seconds());
+            score.record_frame(&main_resource);
+        }
+
         if main_resource.is_landed && !main_resource.victory {
-            for (_ship, transform) in (&ships, &transforms).join() {
-                let ship_polygon = main_resource.get_colliders_polygons_for_landing(
-                    transform.translation().x,
-                    transform.translation().y,
-                );
-                for (collider, _) in (&colliders, &arrivals).join() {
-                    let struct_polygons = collider.polygons();
-                    if are_colliding(&ship_polygon, struct_polygons)
-                        && main_resource.collected_coin == main_resource.level_config().coin_nb
-                    {
-                        main_resource.victory = true;
-                    }
+            // The sweep leaves the ship just above the pad, so rely on the
+            // platform it landed on rather than on an overlap.
+            let arrival = main_resource
+                .landed_platform
+                .filter(|platform| arrivals.contains(*platform))
+                .and_then(|platform| colliders.get(platform))
+                .filter(|_| objectives.completed());
+            if let Some(collider) = arrival {
+                for (_ship, transform) in (&ships, &transforms).join() {
+                    main_resource.victory = true;
+                    let start = collider.colliders()[0].top_left_point().x;
+                    let end = collider.colliders()[0].top_right_point().x;
+                    let half_width = (end - start) / 2.;
+                    let offset =
+                        (transform.translation().x + 16. - (start + half_width)).abs() / half_width;
+                    let breakdown = ScoreBreakdown::compute(&main_resource, &stats, &score, offset);
+                    leaderboard.submit(LeaderboardRun {
+                        level: main_resource.current_level,
+                        time: score.elapsed,
+                        score: breakdown.total(),
+                        replay_hash: format!("{:016x}", score.replay_hash),
+                    });
+                    score.breakdown = Some(breakdown);
                 }
             }
         }
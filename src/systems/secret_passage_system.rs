@@ -0,0 +1,68 @@
+use crate::entities::collision::{are_colliding, Collider, SecretPassage};
+use crate::entities::ship::ShipParent;
+use crate::resources::main_resource::MainResource;
+use crate::utils::sprites::TILE_SIZE;
+use crate::utils::Point2D;
+use amethyst::core::ecs::{Join, Read, ReadStorage, System, WriteStorage};
+use amethyst::core::{Time, Transform};
+use amethyst::renderer::resources::Tint;
+use std::collections::HashSet;
+
+const REVEALED_ALPHA: f32 = 0.2;
+const FADE_SPEED: f32 = 3.;
+
+pub struct SecretPassageSystem;
+
+impl<'s> System<'s> for SecretPassageSystem {
+    type SystemData = (
+        WriteStorage<'s, SecretPassage>,
+        WriteStorage<'s, Tint>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, Transform>,
+        Read<'s, MainResource>,
+        Read<'s, Time>,
+    );
+
+    fn run(
+        &mut self,
+        (mut secrets, mut tints, ships, transforms, main_resource, time): Self::SystemData,
+    ) {
+        let mut ship_polygons = Vec::new();
+        for (_ship, transform) in (&ships, &transforms).join() {
+            ship_polygons = main_resource
+                .get_colliders_for_collision(transform.translation().x, transform.translation().y);
+        }
+
+        // A passage opens up as soon as the ship is inside any of its tiles.
+        let revealed: HashSet<usize> = (&secrets, &transforms)
+            .join()
+            .filter(|(_, transform)| {
+                let tile = Collider::new(
+                    Point2D {
+                        x: transform.translation().x,
+                        y: transform.translation().y,
+                    },
+                    TILE_SIZE,
+                    -TILE_SIZE,
+                );
+                are_colliding(&ship_polygons, &vec![tile.to_polygon()])
+            })
+            .map(|(secret, _)| secret.passage)
+            .collect();
+
+        let step = FADE_SPEED * time.delta_seconds();
+        for (secret, tint) in (&mut secrets, &mut tints).join() {
+            let target = if revealed.contains(&secret.passage) {
+                REVEALED_ALPHA
+            } else {
+                1.
+            };
+            if secret.alpha < target {
+                secret.alpha = (secret.alpha + step).min(target);
+            } else {
+                secret.alpha = (secret.alpha - step).max(target);
+            }
+            tint.0.alpha = secret.alpha;
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::resources::events::ShipEvent;
+use crate::resources::main_resource::MainResource;
+use amethyst::core::ecs::{Read, System, SystemData, World, Write};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+
+const HIT_FLASH_DURATION: f32 = 0.3;
+
+#[derive(Default)]
+pub struct ShipEffectsSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for ShipEffectsSystem {
+    type SystemData = (Read<'s, EventChannel<ShipEvent>>, Write<'s, MainResource>);
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(&mut self, (ship_events, mut main_resource): Self::SystemData) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            if let ShipEvent::Damaged { remaining_life, .. } = event {
+                if *remaining_life > 0 {
+                    main_resource.bullet_hit_timer = HIT_FLASH_DURATION;
+                }
+            }
+        }
+    }
+}
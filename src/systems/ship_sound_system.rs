@@ -0,0 +1,51 @@
+use crate::resources::events::ShipEvent;
+use crate::utils::sound::{play_bonus, play_hit, play_land, Sounds};
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::ecs::{Read, ReadExpect, System, SystemData, World};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+
+#[derive(Default)]
+pub struct ShipSoundSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for ShipSoundSystem {
+    type SystemData = (
+        Read<'s, EventChannel<ShipEvent>>,
+        Read<'s, AssetStorage<Source>>,
+        ReadExpect<'s, Sounds>,
+        Option<Read<'s, Output>>,
+    );
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(&mut self, (ship_events, storage, sounds, audio_output): Self::SystemData) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            match event {
+                ShipEvent::Landed { .. } => {
+                    play_land(&*sounds, &storage, audio_output.as_deref());
+                }
+                ShipEvent::BonusTaken { .. } => {
+                    play_bonus(&*sounds, &storage, audio_output.as_deref());
+                }
+                ShipEvent::Damaged { remaining_life, .. } if *remaining_life > 0 => {
+                    play_hit(&*sounds, &storage, audio_output.as_deref());
+                }
+                _ => {}
+            }
+        }
+    }
+}
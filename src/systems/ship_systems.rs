@@ -1,5 +1,6 @@
 use crate::entities::explosion::Explosion;
 use crate::entities::ship::{Ship, ShipParent};
+use crate::resources::campaign::Campaign;
 use crate::resources::main_resource::MainResource;
 use crate::utils::sound::{play_explosion, play_fire, Sounds};
 use crate::utils::sprites::TILE_SIZE;
@@ -48,6 +49,7 @@ impl<'s> System<'s> for ShipSystem {
         Read<'s, AssetStorage<Source>>,
         ReadExpect<'s, Sounds>,
         Option<Read<'s, Output>>,
+        Write<'s, Campaign>,
     );
 
     fn run(
@@ -65,12 +67,14 @@ impl<'s> System<'s> for ShipSystem {
             storage,
             sounds,
             audio_output,
+            mut campaign,
         ): Self::SystemData,
     ) {
         if main_resource.victory {
             return;
         }
         self.play_fire_timer -= time.delta_seconds();
+        main_resource.last_motion = (0., 0.);
         if main_resource.bullet_hit_timer > 0. {
             main_resource.bullet_hit_timer -= time.delta_seconds();
         }
@@ -110,15 +114,12 @@ impl<'s> System<'s> for ShipSystem {
                         main_resource.current_rotation_angle += ANGLE_ROTATION_DEGREE_MODIFIER;
                     }
                 }
-            } else {
-                main_resource.fuel_up(time.delta_seconds());
-                if main_resource.current_rotation_angle > 0. {
-                    transform.prepend_rotation_z_axis(-1. * angle_rotation_modifier_bind_to_pi());
-                    main_resource.current_rotation_angle -= ANGLE_ROTATION_DEGREE_MODIFIER;
-                } else if main_resource.current_rotation_angle < 0. {
-                    transform.prepend_rotation_z_axis(angle_rotation_modifier_bind_to_pi());
-                    main_resource.current_rotation_angle += ANGLE_ROTATION_DEGREE_MODIFIER;
-                }
+            } else if main_resource.current_rotation_angle > 0. {
+                transform.prepend_rotation_z_axis(-1. * angle_rotation_modifier_bind_to_pi());
+                main_resource.current_rotation_angle -= ANGLE_ROTATION_DEGREE_MODIFIER;
+            } else if main_resource.current_rotation_angle < 0. {
+                transform.prepend_rotation_z_axis(angle_rotation_modifier_bind_to_pi());
+                main_resource.current_rotation_angle += ANGLE_ROTATION_DEGREE_MODIFIER;
             }
         }
 
@@ -127,18 +128,39 @@ impl<'s> System<'s> for ShipSystem {
         for (_, transform) in (&ships_parent, &mut transforms).join() {
             ship_transform = (transform.translation().x, transform.translation().y);
             if main_resource.should_be_reset {
-                let config = main_resource.level_config();
-                transform.set_translation_xyz(
-                    config.start_x as f32 * TILE_SIZE - 16.,
-                    (config.height - config.start_y) as f32 * TILE_SIZE,
-                    0.04,
-                );
+                if main_resource.difficulty.restarts_campaign() {
+                    main_resource.campaign_failed = true;
+                    return;
+                }
+                if campaign.lose_ship() {
+                    main_resource.game_over = true;
+                    return;
+                }
+                match main_resource.checkpoint.as_ref() {
+                    Some(checkpoint) => {
+                        transform.set_translation_xyz(checkpoint.x, checkpoint.y, 0.04);
+                    }
+                    None => {
+                        let config = main_resource.level_config();
+                        transform.set_translation_xyz(
+                            config.start_x as f32 * TILE_SIZE - 16.,
+                            (config.height - config.start_y) as f32 * TILE_SIZE,
+                            0.04,
+                        );
+                    }
+                }
                 main_resource.reset();
                 return;
             }
-            if main_resource.y_force != 0. || main_resource.x_force != 0. {
-                transform.append_translation_xyz(main_resource.x_force, main_resource.y_force, 0.);
+            let (mut motion_x, mut motion_y) = (main_resource.x_force, main_resource.y_force);
+            if main_resource.is_landed {
+                motion_x += main_resource.platform_velocity.0;
+                motion_y += main_resource.platform_velocity.1;
+            }
+            if motion_x != 0. || motion_y != 0. {
+                transform.append_translation_xyz(motion_x, motion_y, 0.);
             }
+            main_resource.last_motion = (motion_x, motion_y);
         }
 
         if main_resource.ship_life <= 0 {
@@ -0,0 +1,38 @@
+use crate::resources::events::ShipEvent;
+use crate::resources::stats::ShipStats;
+use amethyst::core::ecs::{Read, System, SystemData, World, Write};
+use amethyst::core::shrev::{EventChannel, ReaderId};
+
+#[derive(Default)]
+pub struct StatsSystem {
+    reader: Option<ReaderId<ShipEvent>>,
+}
+
+impl<'s> System<'s> for StatsSystem {
+    type SystemData = (Read<'s, EventChannel<ShipEvent>>, Write<'s, ShipStats>);
+
+    fn setup(&mut self, world: &mut World) {
+        <Self::SystemData as SystemData>::setup(world);
+        self.reader = Some(
+            world
+                .fetch_mut::<EventChannel<ShipEvent>>()
+                .register_reader(),
+        );
+    }
+
+    fn run(&mut self, (ship_events, mut stats): Self::SystemData) {
+        let reader = self
+            .reader
+            .as_mut()
+            .expect("ShipEvent reader is registered in setup");
+        for event in ship_events.read(reader) {
+            match event {
+                ShipEvent::Collided { .. } => stats.collisions += 1,
+                ShipEvent::Landed { .. } => stats.landings += 1,
+                ShipEvent::BonusTaken { .. } => stats.bonuses_taken += 1,
+                ShipEvent::Damaged { amount, .. } => stats.damage_taken += *amount as usize,
+                ShipEvent::Destroyed { .. } => stats.deaths += 1,
+            }
+        }
+    }
+}
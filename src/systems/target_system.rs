@@ -0,0 +1,72 @@
+use crate::entities::cargo::Tether;
+use crate::entities::particle::ParticlePreset;
+use crate::entities::ship::ShipParent;
+use crate::entities::target::Target;
+use crate::resources::main_resource::MainResource;
+use crate::resources::objectives::Objectives;
+use crate::resources::particles::ParticleBursts;
+use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, Write};
+use amethyst::core::Transform;
+use std::f32::consts::FRAC_PI_2;
+
+const SHIP_REACH: f32 = 24.;
+const POD_REACH: f32 = 16.;
+
+/// Destroys the targets rammed by the ship or by a towed cargo pod.
+pub struct TargetSystem;
+
+impl<'s> System<'s> for TargetSystem {
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, Target>,
+        ReadStorage<'s, Transform>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, Tether>,
+        Read<'s, MainResource>,
+        Write<'s, Objectives>,
+        Write<'s, ParticleBursts>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            targets,
+            transforms,
+            ships,
+            tethers,
+            main_resource,
+            mut objectives,
+            mut particle_bursts,
+        ): Self::SystemData,
+    ) {
+        if main_resource.is_exploding {
+            return;
+        }
+        let mut rams: Vec<((f32, f32), f32)> = Vec::new();
+        for (_, transform) in (&ships, &transforms).join() {
+            rams.push((
+                (
+                    transform.translation().x + 16.,
+                    transform.translation().y - 16.,
+                ),
+                SHIP_REACH,
+            ));
+        }
+        for tether in (&tethers).join() {
+            rams.push((tether.pod(), POD_REACH));
+        }
+
+        for (entity, _, transform) in (&entities, &targets, &transforms).join() {
+            let (x, y) = (transform.translation().x, transform.translation().y);
+            let hit = rams
+                .iter()
+                .any(|((ram_x, ram_y), reach)| (x - ram_x).hypot(y - ram_y) < *reach);
+            if hit {
+                particle_bursts.push(ParticlePreset::Debris, x, y, FRAC_PI_2);
+                objectives.targets_destroyed += 1;
+                let _res = entities.delete(entity);
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::entities::blade_saw::BladeSawHazard;
+use crate::entities::canons::{Bullet, CanonKind};
+use crate::entities::cargo::{Cargo, CargoState, Tether, TetherLink};
+use crate::entities::collision::{are_colliding, Collider, Colliders, Transparent};
+use crate::entities::doors::{DoorState, PlasmaDoor};
+use crate::entities::particle::{ParticlePreset, PARTICLE_SPRITE};
+use crate::entities::ship::ShipParent;
+use crate::resources::main_resource::{MainResource, FRAMES_PER_SECOND, GRAVITY};
+use crate::resources::particles::ParticleBursts;
+use crate::resources::spatial_index::SpatialIndex;
+use crate::utils::sound::{play_explosion, Sounds};
+use crate::utils::sprites::sprite_to_entities::{init_blade_saw_collider, init_bullet_collider};
+use crate::utils::Point2D;
+use amethyst::assets::AssetStorage;
+use amethyst::audio::output::Output;
+use amethyst::audio::Source;
+use amethyst::core::ecs::{
+    Entities, Entity, Join, Read, ReadExpect, ReadStorage, System, Write, WriteStorage,
+};
+use amethyst::core::{Time, Transform};
+use amethyst::renderer::palette::Srgba;
+use amethyst::renderer::resources::Tint;
+use amethyst::renderer::transparent::Transparent as RenderTransparent;
+use amethyst::renderer::SpriteRender;
+use std::f32::consts::FRAC_PI_2;
+
+const SEGMENTS: usize = 8;
+const TETHER_LENGTH: f32 = 64.;
+const ITERATIONS: usize = 6;
+const DAMPING: f32 = 0.99;
+const SHIP_MASS: f32 = 1.;
+const LINK_MASS: f32 = 0.05;
+// Share of the ship's constraint correction turned into momentum.
+const COUPLING: f32 = 0.5;
+const POD_HALF_SIZE: f32 = 8.;
+const LINK_Z: f32 = 0.75;
+
+/// Simulates the tow cable of tethered cargo: the rope swings with the ship,
+/// the pod collides with the level, hazards destroy it and its weight pulls
+/// the ship around.
+pub struct TetherSystem;
+
+impl<'s> System<'s> for TetherSystem {
+    type SystemData = (
+        Entities<'s>,
+        ReadStorage<'s, Cargo>,
+        WriteStorage<'s, Tether>,
+        WriteStorage<'s, TetherLink>,
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, SpriteRender>,
+        WriteStorage<'s, Tint>,
+        WriteStorage<'s, RenderTransparent>,
+        ReadStorage<'s, ShipParent>,
+        ReadStorage<'s, Colliders>,
+        ReadStorage<'s, Bullet>,
+        ReadStorage<'s, BladeSawHazard>,
+        ReadStorage<'s, PlasmaDoor>,
+        ReadStorage<'s, Transparent>,
+        Read<'s, SpatialIndex>,
+        Write<'s, MainResource>,
+        Write<'s, ParticleBursts>,
+        Read<'s, Time>,
+        Read<'s, AssetStorage<Source>>,
+        ReadExpect<'s, Sounds>,
+        Option<Read<'s, Output>>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            cargos,
+            mut tethers,
+            mut links,
+            mut transforms,
+            mut sprites,
+            mut tints,
+            mut render_transparents,
+            ships,
+            colliders,
+            bullets,
+            blade_saws,
+            plasma_doors,
+            transparents,
+            spatial_index,
+            mut main_resource,
+            mut particle_bursts,
+            time,
+            storage,
+            sounds,
+            audio_output,
+        ): Self::SystemData,
+    ) {
+        let delta = time.delta_seconds();
+        let ship_centre = match (&ships, &transforms).join().next() {
+            Some((_, transform)) => (
+                transform.translation().x + 16.,
+                transform.translation().y - 16.,
+            ),
+            None => return,
+        };
+
+        // Drop the ropes of pods that are no longer towed.
+        let mut released: Vec<Entity> = Vec::new();
+        for (entity, cargo, _) in (&entities, &cargos, &tethers).join() {
+            if cargo.state != CargoState::Carried
+                || main_resource.carried_cargo != Some(entity)
+                || main_resource.is_exploding
+            {
+                released.push(entity);
+            }
+        }
+        for entity in released.iter() {
+            tethers.remove(*entity);
+        }
+        for (entity, link) in (&entities, &links).join() {
+            if !tethers.contains(link.cargo) {
+                let _res = entities.delete(entity);
+            }
+        }
+
+        let carried = match main_resource.carried_cargo {
+            Some(carried) if !main_resource.is_exploding => carried,
+            _ => return,
+        };
+        let cargo = match cargos.get(carried) {
+            Some(cargo) if cargo.tethered && cargo.state == CargoState::Carried => cargo,
+            _ => return,
+        };
+
+        if !tethers.contains(carried) {
+            let pod = match transforms.get(carried) {
+                Some(transform) => (transform.translation().x, transform.translation().y),
+                None => return,
+            };
+            let sprite_sheet = match main_resource.sprites.as_ref() {
+                Some(sprites) => sprites.bullet_sprite_render.clone(),
+                None => return,
+            };
+            let tether = Tether::new(ship_centre, pod, SEGMENTS, TETHER_LENGTH);
+            for index in 1..SEGMENTS {
+                let (x, y) = tether.nodes[index];
+                let mut transform = Transform::default();
+                transform.set_translation_xyz(x, y, LINK_Z);
+                entities
+                    .build_entity()
+                    .with(
+                        TetherLink {
+                            cargo: carried,
+                            index,
+                        },
+                        &mut links,
+                    )
+                    .with(transform, &mut transforms)
+                    .with(
+                        SpriteRender {
+                            sprite_sheet: sprite_sheet.clone(),
+                            sprite_number: PARTICLE_SPRITE,
+                        },
+                        &mut sprites,
+                    )
+                    .with(Tint(Srgba::new(0.8, 0.8, 0.8, 1.)), &mut tints)
+                    .with(RenderTransparent, &mut render_transparents)
+                    .build();
+            }
+            tethers
+                .insert(carried, tether)
+                .expect("Failed to attach the tether");
+        }
+
+        let is_wall = |entity: Entity| {
+            if bullets.contains(entity) || transparents.contains(entity) {
+                return false;
+            }
+            !matches!(
+                plasma_doors.get(entity),
+                Some(PlasmaDoor {
+                    state: DoorState::Open,
+                    ..
+                })
+            )
+        };
+        let hits_wall = |(x, y): (f32, f32)| {
+            let pod = pod_collider(x, y);
+            spatial_index
+                .query_colliders(&pod)
+                .into_iter()
+                .filter(|entity| is_wall(*entity))
+                .filter_map(|entity| colliders.get(entity))
+                .any(|collider| {
+                    collider.aabb().intersects(pod.aabb())
+                        && are_colliding(pod.polygons(), collider.polygons())
+                })
+        };
+
+        let last = SEGMENTS;
+        let mass = cargo.mass;
+        // The rope works in px/s², the ship in px per frame.
+        let gravity = GRAVITY * FRAMES_PER_SECOND as f32;
+        let mut destroyed = false;
+        if let Some(tether) = tethers.get_mut(carried) {
+            tether.nodes[0] = ship_centre;
+            tether.previous[0] = ship_centre;
+            let old_pod = tether.pod();
+            tether.integrate(DAMPING, gravity, delta);
+
+            let inverse_mass = |i: usize| {
+                if i == 0 {
+                    if main_resource.is_landed {
+                        0.
+                    } else {
+                        1. / SHIP_MASS
+                    }
+                } else if i == last {
+                    1. / mass.max(0.01)
+                } else {
+                    1. / LINK_MASS
+                }
+            };
+            for _ in 0..ITERATIONS {
+                tether.relax(inverse_mass);
+                if hits_wall(tether.pod()) {
+                    tether.nodes[last] = old_pod;
+                    tether.previous[last] = old_pod;
+                }
+            }
+
+            let (ship_dx, ship_dy) = (
+                tether.nodes[0].0 - ship_centre.0,
+                tether.nodes[0].1 - ship_centre.1,
+            );
+            tether.nodes[0] = ship_centre;
+            if !main_resource.is_landed {
+                main_resource.x_force += ship_dx * COUPLING;
+                main_resource.y_force += ship_dy * COUPLING;
+            }
+
+            let pod = pod_collider(tether.pod().0, tether.pod().1);
+            destroyed = spatial_index
+                .query_colliders(&pod)
+                .into_iter()
+                .any(|entity| {
+                    let transform = match transforms.get(entity) {
+                        Some(transform) => transform,
+                        None => return false,
+                    };
+                    let (x, y) = (transform.translation().x, transform.translation().y);
+                    let hazard = match (bullets.get(entity), blade_saws.contains(entity)) {
+                        (Some(bullet), _) if !matches!(bullet.kind, CanonKind::Air) => {
+                            init_bullet_collider(&bullet.kind, x, y, bullet.rotation)
+                        }
+                        (_, true) => init_blade_saw_collider(x, y),
+                        _ => return false,
+                    };
+                    hazard.aabb().intersects(pod.aabb())
+                        && are_colliding(pod.polygons(), hazard.polygons())
+                });
+        }
+
+        if let Some(tether) = tethers.get(carried) {
+            let (x, y) = tether.pod();
+            if let Some(transform) = transforms.get_mut(carried) {
+                transform.set_translation_x(x);
+                transform.set_translation_y(y);
+            }
+            for (link, transform) in (&links, &mut transforms).join() {
+                if link.cargo == carried {
+                    let (x, y) = tether.nodes[link.index];
+                    transform.set_translation_x(x);
+                    transform.set_translation_y(y);
+                }
+            }
+            if destroyed {
+                particle_bursts.push(ParticlePreset::Debris, x, y, FRAC_PI_2);
+                play_explosion(&*sounds, &storage, audio_output.as_deref());
+                main_resource.carried_cargo = None;
+                main_resource.cargo_mass = 0.;
+            }
+        }
+    }
+}
+
+fn pod_collider(x: f32, y: f32) -> Colliders {
+    Colliders::from_vec(vec![Collider::new(
+        Point2D {
+            x: x - POD_HALF_SIZE,
+            y: y + POD_HALF_SIZE,
+        },
+        POD_HALF_SIZE * 2.,
+        -POD_HALF_SIZE * 2.,
+    )])
+}
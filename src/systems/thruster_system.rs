@@ -1,8 +1,12 @@
+use crate::entities::particle::ParticleEmitter;
 use crate::entities::ship::Thrusters;
 use crate::resources::main_resource::MainResource;
 use amethyst::core::ecs::{Join, Read, ReadStorage, System, WriteStorage};
 use amethyst::renderer::SpriteRender;
 
+const EXHAUST_RATE_PER_POWER: f32 = 2.;
+const MAX_EXHAUST_POWER: usize = 30;
+
 pub struct ThrustersSystem;
 
 impl<'s> System<'s> for ThrustersSystem {
@@ -10,9 +14,13 @@ impl<'s> System<'s> for ThrustersSystem {
         ReadStorage<'s, Thrusters>,
         WriteStorage<'s, SpriteRender>,
         Read<'s, MainResource>,
+        WriteStorage<'s, ParticleEmitter>,
     );
 
-    fn run(&mut self, (thrusters, mut sprite_renders, main_resource): Self::SystemData) {
+    fn run(
+        &mut self,
+        (thrusters, mut sprite_renders, main_resource, mut emitters): Self::SystemData,
+    ) {
         for (_, sprite_render) in (&thrusters, &mut sprite_renders).join() {
             if main_resource.is_exploding {
                 sprite_render.sprite_number = 5;
@@ -30,5 +38,12 @@ impl<'s> System<'s> for ThrustersSystem {
                 };
             }
         }
+        for (_, emitter) in (&thrusters, &mut emitters).join() {
+            emitter.rate = if main_resource.is_exploding || main_resource.ship_fuel <= 0. {
+                0.
+            } else {
+                main_resource.power.min(MAX_EXHAUST_POWER) as f32 * EXHAUST_RATE_PER_POWER
+            };
+        }
     }
 }
@@ -0,0 +1,145 @@
+use crate::entities::canons::{
+    canon_to_shooting_timer, Bullet, CanonKind, HomingMissile, Turret, MISSILE_FUEL,
+    MISSILE_TURN_RATE,
+};
+use crate::entities::ship::ShipParent;
+use crate::resources::main_resource::MainResource;
+use crate::utils::sprites::sprite_to_entities::bullet_sprite_and_rotation;
+use crate::utils::{
+    angle_between_two_points, angle_difference, distance_between_two_points, rotate_towards,
+};
+use amethyst::core::ecs::{Entities, Join, Read, ReadStorage, System, WriteStorage};
+use amethyst::core::{Time, Transform};
+use amethyst::renderer::SpriteRender;
+use rand::Rng;
+use std::collections::HashMap;
+
+const MUZZLE_LENGTH: f32 = 18.;
+const AIM_TOLERANCE: f32 = 0.15;
+
+pub struct TurretSystem {
+    shooting_timers: HashMap<u32, f32>,
+}
+
+impl Default for TurretSystem {
+    fn default() -> Self {
+        TurretSystem {
+            shooting_timers: HashMap::new(),
+        }
+    }
+}
+
+impl<'s> System<'s> for TurretSystem {
+    type SystemData = (
+        WriteStorage<'s, Turret>,
+        ReadStorage<'s, ShipParent>,
+        WriteStorage<'s, Bullet>,
+        WriteStorage<'s, HomingMissile>,
+        WriteStorage<'s, Transform>,
+        WriteStorage<'s, SpriteRender>,
+        Read<'s, Time>,
+        Read<'s, MainResource>,
+        Entities<'s>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            mut turrets,
+            ships,
+            mut bullets,
+            mut missiles,
+            mut transforms,
+            mut sprite_renders,
+            time,
+            resource,
+            entities,
+        ): Self::SystemData,
+    ) {
+        let ship_center = (&ships, &transforms)
+            .join()
+            .map(|(_, transform)| {
+                (
+                    transform.translation().x + 16.,
+                    transform.translation().y - 16.,
+                )
+            })
+            .last();
+        let (ship_x, ship_y) = match ship_center {
+            Some(center) => center,
+            None => return,
+        };
+
+        let mut to_fire: Vec<(CanonKind, f32, f32, f32)> = Vec::new();
+        for (turret, transform, entity) in (&mut turrets, &mut transforms, &entities).join() {
+            let (x, y) = (transform.translation().x, transform.translation().y);
+            if distance_between_two_points(ship_x, ship_y, x, y) > turret.range
+                || resource.is_exploding
+            {
+                continue;
+            }
+            let target = angle_between_two_points(x, y, ship_x, ship_y);
+            turret.angle = rotate_towards(
+                turret.angle,
+                target,
+                turret.turn_rate * time.delta_seconds(),
+            );
+            transform.set_rotation_z_axis(turret.angle);
+
+            *self
+                .shooting_timers
+                .entry(entity.id())
+                .or_insert(rand::thread_rng().gen_range(0.5, 2.5)) -= time.delta_seconds();
+            let val = self.shooting_timers.get(&entity.id()).unwrap();
+            if val <= &0. && angle_difference(turret.angle, target).abs() < AIM_TOLERANCE {
+                to_fire.push((
+                    turret.kind.clone(),
+                    turret.angle,
+                    x + MUZZLE_LENGTH * turret.angle.cos(),
+                    y + MUZZLE_LENGTH * turret.angle.sin(),
+                ));
+                self.shooting_timers.insert(
+                    entity.id(),
+                    canon_to_shooting_timer(&turret.kind)
+                        * resource.difficulty.shooting_timer_scale(),
+                );
+            }
+        }
+
+        for (kind, angle, x, y) in to_fire {
+            let (sprite_number, rotation) = bullet_sprite_and_rotation(&kind, angle);
+            let mut bullet_transform = Transform::default();
+            bullet_transform.set_translation_xyz(x, y, 0.9);
+            bullet_transform.set_rotation_z_axis(rotation);
+            let mut builder = entities
+                .build_entity()
+                .with(
+                    Bullet::new(&kind, angle, resource.difficulty.bullet_speed_scale()),
+                    &mut bullets,
+                )
+                .with(
+                    SpriteRender {
+                        sprite_sheet: resource
+                            .sprites
+                            .as_ref()
+                            .unwrap()
+                            .bullet_sprite_render
+                            .clone(),
+                        sprite_number,
+                    },
+                    &mut sprite_renders,
+                )
+                .with(bullet_transform, &mut transforms);
+            if let CanonKind::Missile = kind {
+                builder = builder.with(
+                    HomingMissile {
+                        fuel: MISSILE_FUEL,
+                        turn_rate: MISSILE_TURN_RATE,
+                    },
+                    &mut missiles,
+                );
+            }
+            builder.build();
+        }
+    }
+}
@@ -1,7 +1,12 @@
-use crate::entities::ship::{Coin, ShipFuel, ShipLife, ShipPowerLeftNumber, ShipPowerRightNumber};
-use crate::resources::main_resource::MainResource;
+use crate::entities::ship::{
+    Coin, ObjectivesHud, ShipFuel, ShipLife, ShipPowerLeftNumber, ShipPowerRightNumber, ShipsLeft,
+};
+use crate::resources::campaign::Campaign;
+use crate::resources::fuel::FuelStatus;
+use crate::resources::main_resource::{MainResource, MAX_FUEL};
+use crate::resources::objectives::{ObjectiveStatus, Objectives};
 use amethyst::core::ecs::{Join, Read, ReadStorage, System, WriteStorage};
-use amethyst::ui::UiImage;
+use amethyst::ui::{UiImage, UiText};
 use core::cmp;
 
 pub struct UISystem;
@@ -15,11 +20,31 @@ impl<'s> System<'s> for UISystem {
         ReadStorage<'s, ShipLife>,
         ReadStorage<'s, Coin>,
         ReadStorage<'s, ShipFuel>,
+        ReadStorage<'s, ShipsLeft>,
+        WriteStorage<'s, UiText>,
+        Read<'s, Campaign>,
+        Read<'s, FuelStatus>,
+        ReadStorage<'s, ObjectivesHud>,
+        Read<'s, Objectives>,
     );
 
     fn run(
         &mut self,
-        (main_resource, left_powers, right_powers, mut ui_images, lifes, coins, fuels): Self::SystemData,
+        (
+            main_resource,
+            left_powers,
+            right_powers,
+            mut ui_images,
+            lifes,
+            coins,
+            fuels,
+            ships_left,
+            mut ui_texts,
+            campaign,
+            fuel_status,
+            objectives_huds,
+            objectives,
+        ): Self::SystemData,
     ) {
         let (left, right) = format_force(main_resource.x_force, main_resource.y_force);
         for (image, _) in (&mut ui_images, &left_powers).join() {
@@ -39,10 +64,19 @@ impl<'s> System<'s> for UISystem {
             }
         }
 
+        // Ten gauge points whatever the size of the tank.
+        let fuel_per_point = 48. * main_resource.difficulty.fuel_capacity() / MAX_FUEL;
         for (fuel, image) in (&fuels, &mut ui_images).join() {
             match image {
                 UiImage::Sprite(sprite) => {
-                    if main_resource.ship_fuel / 48. < fuel.fuel_point as f32 {
+                    let filling = fuel_status.refuelling
+                        && fuel_status.fill_indicator
+                        && (main_resource.ship_fuel / fuel_per_point) as usize + 1
+                            == fuel.fuel_point as usize;
+                    if !filling
+                        && (!fuel_status.gauge_visible
+                            || main_resource.ship_fuel / fuel_per_point < fuel.fuel_point as f32)
+                    {
                         sprite.sprite_number = 1;
                     } else {
                         sprite.sprite_number = 0;
@@ -77,6 +111,30 @@ impl<'s> System<'s> for UISystem {
                 _ => {}
             }
         }
+
+        for (_, text) in (&ships_left, &mut ui_texts).join() {
+            text.text = format!("Ships x{}", campaign.extra_ships);
+        }
+
+        for (_, text) in (&objectives_huds, &mut ui_texts).join() {
+            text.text = objectives
+                .entries
+                .iter()
+                .map(|(objective, status)| {
+                    let mark = match status {
+                        ObjectiveStatus::Pending => "[ ]",
+                        ObjectiveStatus::Done => "[x]",
+                        ObjectiveStatus::Failed => "[!]",
+                    };
+                    format!(
+                        "{} {}",
+                        mark,
+                        objective.describe(main_resource.level_config())
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+        }
     }
 }
 
@@ -0,0 +1,76 @@
+use crate::entities::zone::{ZoneKind, ZoneParticle};
+use crate::utils::{angle_between_two_points, distance_between_two_points};
+use amethyst::core::ecs::{Join, Read, ReadStorage, System, WriteStorage};
+use amethyst::core::{Time, Transform};
+use rand::Rng;
+
+const WIND_PARTICLE_SPEED_FACTOR: f32 = 30.;
+const WELL_PARTICLE_SPEED: f32 = 40.;
+const LOW_GRAVITY_PARTICLE_SPEED: f32 = 15.;
+const RESPAWN_JITTER: f32 = 4.;
+
+pub struct ZoneParticleSystem;
+
+impl<'s> System<'s> for ZoneParticleSystem {
+    type SystemData = (
+        ReadStorage<'s, ZoneParticle>,
+        WriteStorage<'s, Transform>,
+        Read<'s, Time>,
+    );
+
+    fn run(&mut self, (particles, mut transforms, time): Self::SystemData) {
+        let mut rng = rand::thread_rng();
+        for (particle, transform) in (&particles, &mut transforms).join() {
+            let bounds = &particle.bounds;
+            let (center_x, center_y) = bounds.center();
+            let (x, y) = (transform.translation().x, transform.translation().y);
+            let (velocity_x, velocity_y) = match particle.zone_kind {
+                ZoneKind::Wind { x_force, y_force } => (
+                    x_force * WIND_PARTICLE_SPEED_FACTOR,
+                    y_force * WIND_PARTICLE_SPEED_FACTOR,
+                ),
+                ZoneKind::GravityWell { strength, .. } => {
+                    let angle = angle_between_two_points(x, y, center_x, center_y);
+                    let speed = WELL_PARTICLE_SPEED * strength.signum();
+                    (speed * angle.cos(), speed * angle.sin())
+                }
+                ZoneKind::LowGravity { .. } => (0., LOW_GRAVITY_PARTICLE_SPEED),
+            };
+            let (mut x, mut y) = (
+                x + velocity_x * time.delta_seconds(),
+                y + velocity_y * time.delta_seconds(),
+            );
+
+            if let ZoneKind::GravityWell { strength, .. } = particle.zone_kind {
+                let reached_center = distance_between_two_points(x, y, center_x, center_y) < 4.;
+                if strength > 0. && reached_center {
+                    x = rng.gen_range(bounds.min_x, bounds.max_x);
+                    y = if rng.gen::<bool>() {
+                        bounds.min_y
+                    } else {
+                        bounds.max_y
+                    };
+                } else if strength <= 0. && !bounds.contains(x, y) {
+                    x = center_x + rng.gen_range(-RESPAWN_JITTER, RESPAWN_JITTER);
+                    y = center_y + rng.gen_range(-RESPAWN_JITTER, RESPAWN_JITTER);
+                }
+            } else {
+                if x < bounds.min_x {
+                    x += bounds.width();
+                } else if x > bounds.max_x {
+                    x -= bounds.width();
+                }
+                if y < bounds.min_y {
+                    y += bounds.height();
+                } else if y > bounds.max_y {
+                    y -= bounds.height();
+                }
+            }
+            transform.set_translation_x(x);
+            transform.set_translation_y(y);
+            if velocity_x != 0. || velocity_y != 0. {
+                transform.set_rotation_z_axis(velocity_y.atan2(velocity_x));
+            }
+        }
+    }
+}
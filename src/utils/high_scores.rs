@@ -0,0 +1,269 @@
+use crate::states::next_level::level_title;
+use amethyst::utils::application_root_dir;
+use serde::{Deserialize, Serialize};
+use serde_json::{from_reader, to_string_pretty};
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io::{BufReader, Write};
+use std::path::Path;
+
+const HIGH_SCORES_PATH: &str = "assets/.starlight_scores";
+const CORRUPTED_HIGH_SCORES_PATH: &str = "assets/.starlight_scores.corrupted";
+pub const HIGH_SCORES_LEN: usize = 10;
+pub const NAME_LEN: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HighScoreTable {
+    Level(usize),
+    Campaign,
+}
+
+impl HighScoreTable {
+    pub fn title(&self) -> String {
+        match self {
+            HighScoreTable::Level(level_nb) => level_title(*level_nb),
+            HighScoreTable::Campaign => "Campaign".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct HighScoreEntry {
+    pub name: String,
+    pub score: u32,
+}
+
+/// Named top runs, for display only: the best score of a level is the one
+/// kept in the save by `record_best_score`.
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct HighScores {
+    #[serde(default)]
+    levels: HashMap<usize, Vec<HighScoreEntry>>,
+    #[serde(default)]
+    campaign: Vec<HighScoreEntry>,
+}
+
+impl HighScores {
+    pub fn entries(&self, table: HighScoreTable) -> &[HighScoreEntry] {
+        match table {
+            HighScoreTable::Level(level_nb) => self
+                .levels
+                .get(&level_nb)
+                .map(|entries| entries.as_slice())
+                .unwrap_or(&[]),
+            HighScoreTable::Campaign => &self.campaign,
+        }
+    }
+
+    pub fn qualifies(&self, table: HighScoreTable, score: u32) -> bool {
+        let entries = self.entries(table);
+        score > 0
+            && (entries.len() < HIGH_SCORES_LEN
+                || entries
+                    .last()
+                    .map(|entry| score > entry.score)
+                    .unwrap_or(true))
+    }
+
+    pub fn insert(&mut self, table: HighScoreTable, entry: HighScoreEntry) {
+        let entries = match table {
+            HighScoreTable::Level(level_nb) => self.levels.entry(level_nb).or_default(),
+            HighScoreTable::Campaign => &mut self.campaign,
+        };
+        let position = entries
+            .iter()
+            .position(|other| entry.score > other.score)
+            .unwrap_or_else(|| entries.len());
+        entries.insert(position, entry);
+        entries.truncate(HIGH_SCORES_LEN);
+    }
+
+    /// Drops anything a hand-edited or truncated file could have left behind.
+    fn sanitize(&mut self) {
+        for entries in self
+            .levels
+            .values_mut()
+            .chain(std::iter::once(&mut self.campaign))
+        {
+            entries.retain(|entry| !entry.name.is_empty());
+            for entry in entries.iter_mut() {
+                entry.name = entry.name.chars().take(NAME_LEN).collect();
+            }
+            entries.sort_by(|a, b| b.score.cmp(&a.score));
+            entries.truncate(HIGH_SCORES_LEN);
+        }
+    }
+}
+
+pub fn read_high_scores() -> HighScores {
+    let app_root = application_root_dir().unwrap();
+    read_high_scores_from(
+        &app_root.join(HIGH_SCORES_PATH),
+        &app_root.join(CORRUPTED_HIGH_SCORES_PATH),
+    )
+}
+
+/// A corrupted file is moved aside so the tables start over instead of
+/// failing every time they are read.
+fn read_high_scores_from(path: &Path, corrupted_path: &Path) -> HighScores {
+    if !path.exists() {
+        return HighScores::default();
+    }
+    let parsed = File::open(path)
+        .ok()
+        .and_then(|file| from_reader::<_, HighScores>(BufReader::new(file)).ok());
+    match parsed {
+        Some(mut high_scores) => {
+            high_scores.sanitize();
+            high_scores
+        }
+        None => {
+            let _res = fs::rename(path, corrupted_path);
+            HighScores::default()
+        }
+    }
+}
+
+pub fn save_high_scores(high_scores: &HighScores) {
+    let app_root = application_root_dir().unwrap();
+    let output_path = app_root.join(HIGH_SCORES_PATH);
+    let tmp_path = output_path.with_extension("tmp");
+    if let Ok(mut target_file) = File::create(&tmp_path) {
+        let to_save = to_string_pretty(high_scores).unwrap();
+        if target_file.write_all(to_save.as_bytes()).is_ok() {
+            let _res = fs::rename(&tmp_path, &output_path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry(name: &str, score: u32) -> HighScoreEntry {
+        HighScoreEntry {
+            name: name.to_string(),
+            score,
+        }
+    }
+
+    fn scores(entries: &[HighScoreEntry]) -> Vec<u32> {
+        entries.iter().map(|entry| entry.score).collect()
+    }
+
+    /// Paths of a scores file and of its corrupted copy, unique to the test.
+    fn test_paths(name: &str) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!(
+            "starlight_high_scores_{}_{}",
+            std::process::id(),
+            name
+        ));
+        let _res = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        (dir.join("scores"), dir.join("scores.corrupted"))
+    }
+
+    #[test]
+    fn corrupted_file_is_moved_aside() {
+        for (name, content) in &[
+            ("truncated", "{\"levels\": {\"1\": [{\"name\": \"AB"),
+            ("invalid", "not json at all"),
+        ] {
+            let (path, corrupted_path) = test_paths(name);
+            fs::write(&path, content).unwrap();
+            let high_scores = read_high_scores_from(&path, &corrupted_path);
+            assert!(high_scores.entries(HighScoreTable::Level(1)).is_empty());
+            assert!(high_scores.entries(HighScoreTable::Campaign).is_empty());
+            assert!(!path.exists());
+            assert_eq!(fs::read_to_string(&corrupted_path).unwrap(), *content);
+        }
+    }
+
+    #[test]
+    fn missing_file_gives_empty_tables() {
+        let (path, corrupted_path) = test_paths("missing");
+        let high_scores = read_high_scores_from(&path, &corrupted_path);
+        assert!(high_scores.entries(HighScoreTable::Campaign).is_empty());
+        assert!(!corrupted_path.exists());
+    }
+
+    #[test]
+    fn sanitize_cuts_names_to_name_len() {
+        let mut high_scores = HighScores {
+            campaign: vec![entry("ABCDEF", 10), entry("", 5)],
+            ..HighScores::default()
+        };
+        high_scores.sanitize();
+        let entries = high_scores.entries(HighScoreTable::Campaign);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "ABC");
+    }
+
+    #[test]
+    fn sanitize_sorts_and_truncates_tables() {
+        let mut high_scores = HighScores::default();
+        high_scores.levels.insert(
+            2,
+            (0..HIGH_SCORES_LEN as u32 + 5)
+                .map(|score| entry("AAA", score * 7 % 13))
+                .collect(),
+        );
+        high_scores.sanitize();
+        let entries = scores(high_scores.entries(HighScoreTable::Level(2)));
+        assert_eq!(entries.len(), HIGH_SCORES_LEN);
+        assert!(entries.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(entries[0], 12);
+    }
+
+    #[test]
+    fn sanitized_file_is_read_back_sorted() {
+        let (path, corrupted_path) = test_paths("unsorted");
+        fs::write(
+            &path,
+            "{\"campaign\": [{\"name\": \"LOW\", \"score\": 1}, {\"name\": \"HIGHEST\", \"score\": 9}]}",
+        )
+        .unwrap();
+        let high_scores = read_high_scores_from(&path, &corrupted_path);
+        let entries = high_scores.entries(HighScoreTable::Campaign);
+        assert_eq!(scores(entries), vec![9, 1]);
+        assert_eq!(entries[0].name, "HIG");
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn insert_keeps_descending_order() {
+        let mut high_scores = HighScores::default();
+        for score in &[30, 10, 50, 20, 40, 20] {
+            high_scores.insert(HighScoreTable::Level(1), entry("AAA", *score));
+        }
+        assert_eq!(
+            scores(high_scores.entries(HighScoreTable::Level(1))),
+            vec![50, 40, 30, 20, 20, 10]
+        );
+    }
+
+    #[test]
+    fn insert_truncates_to_high_scores_len() {
+        let mut high_scores = HighScores::default();
+        for score in 1..=HIGH_SCORES_LEN as u32 + 3 {
+            high_scores.insert(HighScoreTable::Campaign, entry("AAA", score));
+        }
+        let entries = scores(high_scores.entries(HighScoreTable::Campaign));
+        assert_eq!(entries.len(), HIGH_SCORES_LEN);
+        assert_eq!(entries[HIGH_SCORES_LEN - 1], 4);
+    }
+
+    #[test]
+    fn qualifies_only_above_a_full_table() {
+        let mut high_scores = HighScores::default();
+        assert!(!high_scores.qualifies(HighScoreTable::Campaign, 0));
+        assert!(high_scores.qualifies(HighScoreTable::Campaign, 1));
+        for score in 1..=HIGH_SCORES_LEN as u32 {
+            high_scores.insert(HighScoreTable::Campaign, entry("AAA", score * 10));
+        }
+        assert!(!high_scores.qualifies(HighScoreTable::Campaign, 10));
+        assert!(high_scores.qualifies(HighScoreTable::Campaign, 11));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::utils::leaderboard::{best_runs, LeaderboardBackend, LeaderboardError, LeaderboardRun};
+use serde_json::{from_reader, to_string_pretty};
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+pub struct FileBackend {
+    path: PathBuf,
+}
+
+impl FileBackend {
+    pub fn new(path: PathBuf) -> Self {
+        FileBackend { path }
+    }
+
+    /// A corrupted file is moved aside so the runs start over instead of
+    /// failing every time they are read.
+    fn read_runs(&self) -> Result<Vec<LeaderboardRun>, LeaderboardError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let file = File::open(&self.path)?;
+        match from_reader(BufReader::new(file)) {
+            Ok(runs) => Ok(runs),
+            Err(_) => {
+                fs::rename(&self.path, self.corrupted_path())?;
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    fn corrupted_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".corrupted");
+        PathBuf::from(path)
+    }
+}
+
+impl LeaderboardBackend for FileBackend {
+    fn submit(&mut self, run: &LeaderboardRun) -> Result<(), LeaderboardError> {
+        let mut runs = self.read_runs()?;
+        runs.push(run.clone());
+        fs::write(&self.path, to_string_pretty(&runs)?)?;
+        Ok(())
+    }
+
+    fn top(&mut self, level: usize, count: usize) -> Result<Vec<LeaderboardRun>, LeaderboardError> {
+        Ok(best_runs(&self.read_runs()?, level, count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(level: usize, score: u32) -> LeaderboardRun {
+        LeaderboardRun {
+            level,
+            time: 10.,
+            score,
+            replay_hash: format!("{:016x}", score),
+        }
+    }
+
+    /// A backend on a file of its own, with `content` when given.
+    fn backend(name: &str, content: Option<&str>) -> FileBackend {
+        let dir =
+            std::env::temp_dir().join(format!("starlight_runs_{}_{}", std::process::id(), name));
+        let _res = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("runs");
+        if let Some(content) = content {
+            fs::write(&path, content).unwrap();
+        }
+        FileBackend::new(path)
+    }
+
+    #[test]
+    fn submitted_runs_are_read_back() {
+        let mut backend = backend("round_trip", None);
+        backend.submit(&run(1, 300)).unwrap();
+        backend.submit(&run(1, 500)).unwrap();
+        assert_eq!(backend.top(1, 5).unwrap(), vec![run(1, 500), run(1, 300)]);
+    }
+
+    #[test]
+    fn corrupted_file_is_moved_aside() {
+        let mut backend = backend("corrupted", Some("[{\"level\": 1, \"sco"));
+        assert_eq!(backend.top(1, 5).unwrap(), Vec::new());
+        assert_eq!(
+            fs::read_to_string(backend.corrupted_path()).unwrap(),
+            "[{\"level\": 1, \"sco"
+        );
+        backend.submit(&run(1, 300)).unwrap();
+        assert_eq!(backend.top(1, 5).unwrap(), vec![run(1, 300)]);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::utils::leaderboard::{LeaderboardBackend, LeaderboardError, LeaderboardRun};
+use serde_json::{from_slice, to_vec};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Talks to a leaderboard over plain HTTP/1.1:
+/// `POST /runs` with a run as JSON, and `GET /runs?level=..&count=..`
+/// answering a JSON array of runs.
+pub struct HttpBackend {
+    address: String,
+}
+
+impl HttpBackend {
+    pub fn new(address: String) -> Self {
+        HttpBackend { address }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &[u8]) -> Result<Vec<u8>, LeaderboardError> {
+        let mut stream = TcpStream::connect(&self.address)?;
+        stream.set_read_timeout(Some(TIMEOUT))?;
+        stream.set_write_timeout(Some(TIMEOUT))?;
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            method,
+            path,
+            self.address,
+            body.len()
+        )?;
+        stream.write_all(body)?;
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+        let header_end = response
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .ok_or_else(|| LeaderboardError::Parse("missing response headers".to_string()))?;
+        let status = String::from_utf8_lossy(&response[..header_end])
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse::<u16>().ok())
+            .ok_or_else(|| LeaderboardError::Parse("missing response status".to_string()))?;
+        if !(200..300).contains(&status) {
+            return Err(LeaderboardError::Status(status));
+        }
+        Ok(response[header_end + 4..].to_vec())
+    }
+}
+
+impl LeaderboardBackend for HttpBackend {
+    fn submit(&mut self, run: &LeaderboardRun) -> Result<(), LeaderboardError> {
+        self.request("POST", "/runs", &to_vec(run)?)?;
+        Ok(())
+    }
+
+    fn top(&mut self, level: usize, count: usize) -> Result<Vec<LeaderboardRun>, LeaderboardError> {
+        let path = format!("/runs?level={}&count={}", level, count);
+        let body = self.request("GET", &path, &[])?;
+        Ok(from_slice(&body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::leaderboard::stub_server::StubServer;
+
+    fn run(level: usize, time: f32, score: u32) -> LeaderboardRun {
+        LeaderboardRun {
+            level,
+            time,
+            score,
+            replay_hash: format!("{:016x}", score),
+        }
+    }
+
+    #[test]
+    fn submitted_runs_reach_the_server() {
+        let server = StubServer::start().unwrap();
+        let mut backend = HttpBackend::new(server.address());
+        backend.submit(&run(0, 12.5, 800)).unwrap();
+        backend.submit(&run(1, 20., 300)).unwrap();
+        assert_eq!(server.runs(), vec![run(0, 12.5, 800), run(1, 20., 300)]);
+    }
+
+    #[test]
+    fn top_orders_by_score_then_time() {
+        let server = StubServer::start().unwrap();
+        let mut backend = HttpBackend::new(server.address());
+        for submitted in &[
+            run(2, 30., 500),
+            run(2, 25., 900),
+            run(3, 10., 1000),
+            run(2, 20., 500),
+        ] {
+            backend.submit(submitted).unwrap();
+        }
+        assert_eq!(
+            backend.top(2, 2).unwrap(),
+            vec![run(2, 25., 900), run(2, 20., 500)]
+        );
+        assert_eq!(backend.top(4, 5).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn unknown_paths_are_not_found() {
+        let server = StubServer::start().unwrap();
+        let backend = HttpBackend::new(server.address());
+        match backend.request("GET", "/scores", &[]) {
+            Err(LeaderboardError::Status(404)) => {}
+            other => panic!("expected a 404, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn malformed_requests_are_rejected() {
+        let server = StubServer::start().unwrap();
+        let backend = HttpBackend::new(server.address());
+        match backend.request("POST", "/runs", b"not a run") {
+            Err(LeaderboardError::Status(400)) => {}
+            other => panic!("expected a 400, got {:?}", other),
+        }
+        match backend.request("GET", "/runs?level=1", &[]) {
+            Err(LeaderboardError::Status(400)) => {}
+            other => panic!("expected a 400, got {:?}", other),
+        }
+        assert!(server.runs().is_empty());
+    }
+
+    #[test]
+    fn oversized_bodies_are_refused() {
+        let server = StubServer::start().unwrap();
+        let mut stream = TcpStream::connect(server.address()).unwrap();
+        write!(
+            stream,
+            "POST /runs HTTP/1.1\r\nContent-Length: 1000000000000\r\n\r\n"
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(
+            response.starts_with("HTTP/1.1 413"),
+            "expected a 413, got {:?}",
+            response
+        );
+        assert!(server.runs().is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+
+pub mod file;
+pub mod http;
+pub mod stub_server;
+
+/// A finished level as sent to a leaderboard. `replay_hash` fingerprints the
+/// ship state of every frame so a backend can tell replayed runs apart.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LeaderboardRun {
+    pub level: usize,
+    pub time: f32,
+    pub score: u32,
+    pub replay_hash: String,
+}
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    Io(io::Error),
+    Status(u16),
+    Parse(String),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaderboardError::Io(e) => write!(f, "leaderboard io error: {}", e),
+            LeaderboardError::Status(status) => write!(f, "leaderboard answered {}", status),
+            LeaderboardError::Parse(e) => write!(f, "invalid leaderboard data: {}", e),
+        }
+    }
+}
+
+impl From<io::Error> for LeaderboardError {
+    fn from(e: io::Error) -> Self {
+        LeaderboardError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for LeaderboardError {
+    fn from(e: serde_json::Error) -> Self {
+        LeaderboardError::Parse(e.to_string())
+    }
+}
+
+/// Backends are blocking; the `Leaderboard` resource runs them off the
+/// frame loop.
+pub trait LeaderboardBackend: Send {
+    fn submit(&mut self, run: &LeaderboardRun) -> Result<(), LeaderboardError>;
+    fn top(&mut self, level: usize, count: usize) -> Result<Vec<LeaderboardRun>, LeaderboardError>;
+}
+
+pub fn best_runs(runs: &[LeaderboardRun], level: usize, count: usize) -> Vec<LeaderboardRun> {
+    let mut best: Vec<LeaderboardRun> = runs
+        .iter()
+        .filter(|run| run.level == level)
+        .cloned()
+        .collect();
+    best.sort_by(|a, b| {
+        b.score.cmp(&a.score).then(
+            a.time
+                .partial_cmp(&b.time)
+                .unwrap_or(std::cmp::Ordering::Equal),
+        )
+    });
+    best.truncate(count);
+    best
+}
@@ -0,0 +1,139 @@
+use crate::utils::leaderboard::{best_runs, LeaderboardRun};
+use serde_json::{from_slice, to_vec};
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+
+/// Runs are a few hundred bytes: anything much larger is not a run.
+const MAX_BODY_LEN: usize = 64 * 1024;
+
+/// In-process leaderboard speaking the same protocol as `HttpBackend`,
+/// for local play and for exercising the HTTP client without a network.
+pub struct StubServer {
+    address: SocketAddr,
+    runs: Arc<Mutex<Vec<LeaderboardRun>>>,
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl StubServer {
+    pub fn start() -> io::Result<StubServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        let runs = Arc::new(Mutex::new(Vec::new()));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let runs = runs.clone();
+            let stopped = stopped.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let _res = handle_connection(stream, &runs);
+                    }
+                }
+            })
+        };
+        Ok(StubServer {
+            address,
+            runs,
+            stopped,
+            handle: Some(handle),
+        })
+    }
+
+    pub fn address(&self) -> String {
+        self.address.to_string()
+    }
+
+    pub fn runs(&self) -> Vec<LeaderboardRun> {
+        self.runs.lock().unwrap().clone()
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Wakes the accept loop up so it can see the flag.
+        let _res = TcpStream::connect(self.address);
+        if let Some(handle) = self.handle.take() {
+            let _res = handle.join();
+        }
+    }
+}
+
+fn handle_connection(stream: TcpStream, runs: &Mutex<Vec<LeaderboardRun>>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        let mut parts = header.splitn(2, ':');
+        if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    if content_length > MAX_BODY_LEN {
+        return write_response(stream, "413 Payload Too Large", &[]);
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let mut words = request_line.split_whitespace();
+    let (status, response) = match (words.next(), words.next()) {
+        (Some("POST"), Some("/runs")) => match from_slice::<LeaderboardRun>(&body) {
+            Ok(run) => {
+                runs.lock().unwrap().push(run);
+                ("201 Created", Vec::new())
+            }
+            Err(_) => ("400 Bad Request", Vec::new()),
+        },
+        (Some("GET"), Some(path)) if path.starts_with("/runs?") => {
+            let query = |key: &str| {
+                path["/runs?".len()..]
+                    .split('&')
+                    .filter_map(|pair| {
+                        let mut pair = pair.splitn(2, '=');
+                        match (pair.next(), pair.next()) {
+                            (Some(name), Some(value)) if name == key => value.parse().ok(),
+                            _ => None,
+                        }
+                    })
+                    .next()
+            };
+            match (query("level"), query("count")) {
+                (Some(level), Some(count)) => {
+                    let best = best_runs(&runs.lock().unwrap(), level, count);
+                    ("200 OK", to_vec(&best)?)
+                }
+                _ => ("400 Bad Request", Vec::new()),
+            }
+        }
+        _ => ("404 Not Found", Vec::new()),
+    };
+    write_response(stream, status, &response)
+}
+
+fn write_response(mut stream: TcpStream, status: &str, response: &[u8]) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        response.len()
+    )?;
+    stream.write_all(response)
+}
@@ -1,12 +1,27 @@
 use crate::entities::blade_saw::BladeSaw;
+use crate::entities::canons::{CanonKind, CanonObject, Turret};
+use crate::entities::collision::{PadKind, TileBehaviour};
+use crate::entities::path::{Easing, PathConfig, PathKind, PathMode};
+use crate::entities::zone::{ZoneConfig, ZoneKind};
+use crate::resources::objectives::Objective;
 use crate::utils::sprites::sprite_to_entities::COIN;
-use crate::utils::sprites::NO_TILE_ID;
+use crate::utils::sprites::{NO_TILE_ID, TILE_SIZE};
+use crate::utils::Direction;
 use amethyst::core::math::Point3;
 use amethyst::utils::application_root_dir;
 use serde::Deserialize;
 use serde_json::from_reader;
 use std::collections::HashMap;
 use std::fs::File;
+use std::str::FromStr;
+
+const DEFAULT_TURRET_TURN_RATE: f32 = 1.5;
+const DEFAULT_TURRET_RANGE: f32 = 300.;
+const DEFAULT_PATH_SPEED: f32 = 50.;
+const DEFAULT_GRAVITY_WELL_STRENGTH: f32 = 3.;
+const DEFAULT_LOW_GRAVITY: f32 = 0.3;
+const DEFAULT_REFUEL_RATE: f32 = 100.;
+const DEFAULT_CARGO_MASS: f32 = 0.5;
 
 pub fn read_level(lvl_number: usize) -> LevelConfig {
     let input_path = format!("assets/levels/level_{}.json", lvl_number);
@@ -31,7 +46,17 @@ pub struct LevelConfig {
     pub start_x: u32,
     pub start_y: u32,
     pub tiles: HashMap<Point3<u32>, usize>,
+    pub tile_behaviours: HashMap<Point3<u32>, TileBehaviour>,
     pub blade_saws: Vec<BladeSaw>,
+    pub turrets: Vec<Turret>,
+    pub canons: Vec<CanonObject>,
+    pub paths: Vec<PathConfig>,
+    pub zones: Vec<ZoneConfig>,
+    pub checkpoints: Vec<(f32, f32)>,
+    pub pads: Vec<PadConfig>,
+    pub cargos: Vec<CargoConfig>,
+    pub targets: Vec<(f32, f32)>,
+    pub objectives: Vec<Objective>,
     pub text: String,
     pub coin_nb: usize,
 }
@@ -39,10 +64,28 @@ pub struct LevelConfig {
 impl LevelConfig {
     fn new(level: TiledLevel) -> Self {
         let mut tiles: HashMap<Point3<u32>, usize> = HashMap::new();
+        let mut tile_behaviours: HashMap<Point3<u32>, TileBehaviour> = HashMap::new();
+        let mut sprite_behaviours: HashMap<usize, TileBehaviour> = HashMap::new();
+        for tileset in level.tilesets.iter() {
+            for tile in tileset.tiles.iter() {
+                if let Some(behaviour) = read_tile_behaviour(&tile.properties) {
+                    sprite_behaviours.insert(tileset.firstgid + tile.id - 1, behaviour);
+                }
+            }
+        }
         let mut coin_nb = 0;
         let mut blade_saws: Vec<BladeSaw> = Vec::new();
+        let mut turrets: Vec<Turret> = Vec::new();
+        let mut canons: Vec<CanonObject> = Vec::new();
+        let mut paths: Vec<PathConfig> = Vec::new();
+        let mut zones: Vec<ZoneConfig> = Vec::new();
+        let mut checkpoints: Vec<(f32, f32)> = Vec::new();
+        let mut pads: Vec<PadConfig> = Vec::new();
+        let mut cargos: Vec<CargoConfig> = Vec::new();
+        let mut targets: Vec<(f32, f32)> = Vec::new();
         for layer in level.layers {
             let z = get_z_from_layer_name(layer.name.as_str());
+            let layer_behaviour = read_tile_behaviour(&layer.properties);
             if layer.data.is_some() {
                 let layer_data = layer.data.unwrap();
                 for y in 0..level.height {
@@ -62,6 +105,20 @@ impl LevelConfig {
                             if tile_number - 1 == COIN as i32 {
                                 coin_nb += 1;
                             }
+                            if let Some(behaviour) = layer_behaviour.clone().or_else(|| {
+                                sprite_behaviours
+                                    .get(&((tile_number - 1) as usize))
+                                    .cloned()
+                            }) {
+                                if let (TileBehaviour::Secret, true) = (&behaviour, layer.visible) {
+                                    eprintln!(
+                                        "Secret tile ({}, {}) is on the visible layer {}: it is baked into the level image and cannot be revealed",
+                                        tile_x, tile_y, layer.name
+                                    );
+                                }
+                                tile_behaviours
+                                    .insert(Point3::new(tile_x, tile_y, tile_z), behaviour);
+                            }
                         }
                     }
                 }
@@ -143,12 +200,114 @@ impl LevelConfig {
                                 .parse()
                                 .unwrap(),
                         }),
+                        DataType::Turret => turrets.push(read_turret(
+                            &entity.properties,
+                            CanonKind::Bullet,
+                            level.height,
+                        )),
+                        DataType::MissileLauncher => turrets.push(read_turret(
+                            &entity.properties,
+                            CanonKind::Missile,
+                            level.height,
+                        )),
+                        DataType::Canon => {
+                            canons.push(read_canon(&entity.properties, level.height))
+                        }
+                        DataType::BladeSawPath => {
+                            if let Some(path) = read_path(&entity, PathKind::BladeSaw, level.height)
+                            {
+                                paths.push(path);
+                            }
+                        }
+                        DataType::PlatformPath => {
+                            let platform = PathKind::Platform(read_platform_pad(&entity));
+                            if let Some(path) = read_path(&entity, platform, level.height) {
+                                paths.push(path);
+                            }
+                        }
+                        DataType::DronePath => {
+                            let drone = PathKind::Drone(read_drone(&entity, level.height));
+                            if let Some(path) = read_path(&entity, drone, level.height) {
+                                paths.push(path);
+                            }
+                        }
+                        DataType::WindZone => zones.push(read_zone(
+                            &entity,
+                            ZoneKind::Wind {
+                                x_force: get_property(&entity.properties, "x_force").unwrap_or(0.),
+                                y_force: get_property(&entity.properties, "y_force").unwrap_or(0.),
+                            },
+                            level.height,
+                        )),
+                        DataType::GravityWell => zones.push(read_zone(
+                            &entity,
+                            ZoneKind::GravityWell {
+                                strength: get_property(&entity.properties, "strength")
+                                    .unwrap_or(DEFAULT_GRAVITY_WELL_STRENGTH),
+                                radius: get_property(&entity.properties, "radius")
+                                    .unwrap_or(entity.width.max(entity.height)),
+                            },
+                            level.height,
+                        )),
+                        DataType::LowGravityZone => zones.push(read_zone(
+                            &entity,
+                            ZoneKind::LowGravity {
+                                gravity: get_property(&entity.properties, "gravity")
+                                    .unwrap_or(DEFAULT_LOW_GRAVITY),
+                            },
+                            level.height,
+                        )),
+                        DataType::Checkpoint => checkpoints.push(tiled_to_world(
+                            entity.x + TILE_SIZE / 2.,
+                            entity.y + TILE_SIZE / 2.,
+                            level.height,
+                        )),
+                        DataType::RefuelPad => pads.push(read_pad(
+                            &entity,
+                            PadKind::Refuel {
+                                rate: get_property(&entity.properties, "rate")
+                                    .unwrap_or(DEFAULT_REFUEL_RATE),
+                            },
+                            level.height,
+                        )),
+                        DataType::RepairPad => {
+                            pads.push(read_pad(&entity, PadKind::Repair, level.height))
+                        }
+                        DataType::Pad => pads.push(read_pad(&entity, PadKind::Plain, level.height)),
+                        DataType::Target => targets.push(tiled_to_world(
+                            entity.x + TILE_SIZE / 2.,
+                            entity.y + TILE_SIZE / 2.,
+                            level.height,
+                        )),
+                        DataType::DeliveryPad => pads.push(read_pad(
+                            &entity,
+                            PadKind::Delivery {
+                                order: get_property(&entity.properties, "order").unwrap_or(0),
+                            },
+                            level.height,
+                        )),
+                        DataType::Cargo => {
+                            let (x, y) = tiled_to_world(
+                                entity.x + TILE_SIZE / 2.,
+                                entity.y + TILE_SIZE / 2.,
+                                level.height,
+                            );
+                            cargos.push(CargoConfig {
+                                x,
+                                y,
+                                order: get_property(&entity.properties, "order").unwrap_or(0),
+                                mass: get_property(&entity.properties, "mass")
+                                    .unwrap_or(DEFAULT_CARGO_MASS),
+                                tethered: get_property(&entity.properties, "tethered")
+                                    .unwrap_or(false),
+                            })
+                        }
                     }
                 }
             }
         }
 
-        LevelConfig {
+        let mut config = LevelConfig {
             height: level.height,
             width: level.width,
             start_x: level
@@ -170,7 +329,17 @@ impl LevelConfig {
                 .parse()
                 .unwrap(),
             tiles,
+            tile_behaviours,
             blade_saws,
+            turrets,
+            canons,
+            paths,
+            zones,
+            checkpoints,
+            pads,
+            cargos,
+            targets,
+            objectives: Vec::new(),
             text: level
                 .properties
                 .iter()
@@ -180,16 +349,289 @@ impl LevelConfig {
                 .value
                 .to_string(),
             coin_nb,
+        };
+        let objectives: Vec<Objective> =
+            match get_property::<String>(&level.properties, "objectives") {
+                Some(objectives) => objectives
+                    .split(';')
+                    .filter(|objective| !objective.trim().is_empty())
+                    .filter_map(|objective| match objective.parse() {
+                        Ok(objective) => Some(objective),
+                        Err(error) => {
+                            eprintln!("{}", error);
+                            None
+                        }
+                    })
+                    .collect(),
+                None => Vec::new(),
+            };
+        // An empty list would make the arrival win right away.
+        config.objectives = if objectives.is_empty() {
+            Objective::defaults(&config)
+        } else {
+            objectives
+        };
+        config
+    }
+
+    /// Levels made before refuel pads existed refuel on every pad.
+    pub fn has_refuel_pads(&self) -> bool {
+        let platforms = self.paths.iter().filter_map(|path| match path.kind {
+            PathKind::Platform(kind) => Some(kind),
+            _ => None,
+        });
+        self.pads
+            .iter()
+            .map(|pad| pad.kind)
+            .chain(platforms)
+            .any(|kind| matches!(kind, PadKind::Refuel { .. }))
+    }
+
+    /// Groups secret tiles into passages of edge-adjacent tiles on the same
+    /// layer, so a whole passage is revealed at once.
+    pub fn secret_passages(&self) -> HashMap<Point3<u32>, usize> {
+        let mut passages: HashMap<Point3<u32>, usize> = HashMap::new();
+        let mut passage_nb = 0;
+        for (point, behaviour) in self.tile_behaviours.iter() {
+            if !matches!(behaviour, TileBehaviour::Secret) || passages.contains_key(point) {
+                continue;
+            }
+            let mut pending = vec![*point];
+            while let Some(tile) = pending.pop() {
+                if passages.contains_key(&tile) {
+                    continue;
+                }
+                passages.insert(tile, passage_nb);
+                let neighbours = [
+                    (tile.x.wrapping_sub(1), tile.y),
+                    (tile.x + 1, tile.y),
+                    (tile.x, tile.y.wrapping_sub(1)),
+                    (tile.x, tile.y + 1),
+                ];
+                for (x, y) in neighbours.iter() {
+                    let neighbour = Point3::new(*x, *y, tile.z);
+                    if let Some(TileBehaviour::Secret) = self.tile_behaviours.get(&neighbour) {
+                        pending.push(neighbour);
+                    }
+                }
+            }
+            passage_nb += 1;
         }
+        passages
+    }
+
+    /// Rate at which a pad of this kind refuels the ship, if it does.
+    pub fn refuel_rate(&self, kind: PadKind) -> Option<f32> {
+        match kind {
+            PadKind::Refuel { rate } => Some(rate),
+            PadKind::Repair => None,
+            _ if !self.has_refuel_pads() => Some(DEFAULT_REFUEL_RATE),
+            _ => None,
+        }
+    }
+
+    /// Number of pads the `VisitPads` objective asks for.
+    pub fn waypoint_count(&self) -> usize {
+        self.pads.iter().filter(|pad| pad.visit.is_some()).count()
     }
 }
 
+fn read_turret(properties: &Vec<TiledPropery>, kind: CanonKind, height: u32) -> Turret {
+    let x: f32 = get_property(properties, "x").unwrap();
+    let y: f32 = get_property(properties, "y").unwrap();
+    Turret {
+        kind,
+        x,
+        y: height as f32 - y - 1.,
+        angle: get_property::<f32>(properties, "angle")
+            .unwrap_or(0.)
+            .to_radians(),
+        turn_rate: get_property(properties, "turn_rate").unwrap_or(DEFAULT_TURRET_TURN_RATE),
+        range: get_property(properties, "range").unwrap_or(DEFAULT_TURRET_RANGE),
+    }
+}
+
+fn read_canon(properties: &Vec<TiledPropery>, height: u32) -> CanonObject {
+    let x: f32 = get_property(properties, "x").unwrap();
+    let y: f32 = get_property(properties, "y").unwrap();
+    CanonObject {
+        kind: match get_property::<String>(properties, "kind").as_deref() {
+            Some("Smg") => CanonKind::Smg,
+            Some("Air") => CanonKind::Air,
+            _ => CanonKind::Bullet,
+        },
+        x,
+        y: height as f32 - y - 1.,
+        angle: get_property::<f32>(properties, "angle")
+            .unwrap_or(0.)
+            .to_radians(),
+    }
+}
+
+/// Drones are turrets without `x` and `y` properties: they start on their
+/// path's first point.
+fn read_drone(entity: &TiledEntity, height: u32) -> Turret {
+    Turret {
+        kind: match get_property::<String>(&entity.properties, "kind").as_deref() {
+            Some("Missile") => CanonKind::Missile,
+            _ => CanonKind::Bullet,
+        },
+        x: entity.x / TILE_SIZE,
+        y: height as f32 - entity.y / TILE_SIZE - 1.,
+        angle: get_property::<f32>(&entity.properties, "angle")
+            .unwrap_or(0.)
+            .to_radians(),
+        turn_rate: get_property(&entity.properties, "turn_rate")
+            .unwrap_or(DEFAULT_TURRET_TURN_RATE),
+        range: get_property(&entity.properties, "range").unwrap_or(DEFAULT_TURRET_RANGE),
+    }
+}
+
+/// Moving platforms refuel unless their `pad` property says otherwise.
+fn read_platform_pad(entity: &TiledEntity) -> PadKind {
+    match get_property::<String>(&entity.properties, "pad").as_deref() {
+        Some("Plain") => PadKind::Plain,
+        Some("Repair") => PadKind::Repair,
+        _ => PadKind::Refuel {
+            rate: get_property(&entity.properties, "rate").unwrap_or(DEFAULT_REFUEL_RATE),
+        },
+    }
+}
+
+fn read_path(entity: &TiledEntity, kind: PathKind, height: u32) -> Option<PathConfig> {
+    let (relative_points, closed) = match (&entity.polyline, &entity.polygon) {
+        (Some(polyline), _) => (polyline, false),
+        (_, Some(polygon)) => (polygon, true),
+        _ => return None,
+    };
+    let points = relative_points
+        .iter()
+        .map(|point| tiled_to_world(entity.x + point.x, entity.y + point.y, height))
+        .collect();
+    let speeds: Vec<f32> = match get_property::<String>(&entity.properties, "speeds") {
+        Some(speeds) => speeds
+            .split(',')
+            .filter_map(|speed| speed.trim().parse().ok())
+            .collect(),
+        None => vec![get_property(&entity.properties, "speed").unwrap_or(DEFAULT_PATH_SPEED)],
+    };
+    if speeds.is_empty() || speeds.iter().any(|speed| *speed <= 0.) {
+        eprintln!(
+            "Ignoring the path at ({}, {}): speeds must be positive, got {:?}",
+            entity.x, entity.y, speeds
+        );
+        return None;
+    }
+    let mode = match get_property::<String>(&entity.properties, "mode").as_deref() {
+        Some("Loop") => PathMode::Loop,
+        Some("PingPong") => PathMode::PingPong,
+        Some("OneShot") => PathMode::OneShot,
+        _ if closed => PathMode::Loop,
+        _ => PathMode::PingPong,
+    };
+    let easing = match get_property::<String>(&entity.properties, "easing").as_deref() {
+        Some("EaseInOut") => Easing::EaseInOut,
+        _ => Easing::Linear,
+    };
+    Some(PathConfig {
+        kind,
+        points,
+        speeds,
+        mode,
+        easing,
+    })
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PadConfig {
+    pub x: f32,
+    pub y: f32,
+    pub kind: PadKind,
+    pub visit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CargoConfig {
+    pub x: f32,
+    pub y: f32,
+    pub order: usize,
+    pub mass: f32,
+    pub tethered: bool,
+}
+
+fn read_pad(entity: &TiledEntity, kind: PadKind, height: u32) -> PadConfig {
+    let (x, y) = tiled_to_world(entity.x + TILE_SIZE / 2., entity.y + TILE_SIZE / 2., height);
+    PadConfig {
+        x,
+        y,
+        kind,
+        visit: get_property(&entity.properties, "visit"),
+    }
+}
+
+fn read_zone(entity: &TiledEntity, kind: ZoneKind, height: u32) -> ZoneConfig {
+    let relative_points: Vec<(f32, f32)> = match &entity.polygon {
+        Some(polygon) => polygon.iter().map(|point| (point.x, point.y)).collect(),
+        None => vec![
+            (0., 0.),
+            (entity.width, 0.),
+            (entity.width, entity.height),
+            (0., entity.height),
+        ],
+    };
+    ZoneConfig {
+        kind,
+        points: relative_points
+            .iter()
+            .map(|(x, y)| tiled_to_collision(entity.x + x, entity.y + y, height))
+            .collect(),
+        particles: get_property(&entity.properties, "particles").unwrap_or(false),
+    }
+}
+
+pub fn tiled_to_collision(x: f32, y: f32, height: u32) -> (f32, f32) {
+    (x, height as f32 * TILE_SIZE - y - TILE_SIZE)
+}
+
+// Tiled points are in pixels from the top left corner of the map, entities are centered on tiles
+pub fn tiled_to_world(x: f32, y: f32, height: u32) -> (f32, f32) {
+    (
+        x - TILE_SIZE / 2.,
+        height as f32 * TILE_SIZE - y - TILE_SIZE / 2.,
+    )
+}
+
+fn read_tile_behaviour(properties: &Vec<TiledPropery>) -> Option<TileBehaviour> {
+    if let Some(true) = get_property(properties, "secret") {
+        return Some(TileBehaviour::Secret);
+    }
+    match get_property::<String>(properties, "one_way").as_deref() {
+        Some("Left") => return Some(TileBehaviour::OneWay(Direction::Left)),
+        Some("Right") => return Some(TileBehaviour::OneWay(Direction::Right)),
+        Some("Top") => return Some(TileBehaviour::OneWay(Direction::Top)),
+        Some("Bottom") => return Some(TileBehaviour::OneWay(Direction::Bottom)),
+        _ => {}
+    }
+    if let Some(true) = get_property(properties, "transparent") {
+        return Some(TileBehaviour::Transparent);
+    }
+    None
+}
+
+fn get_property<T: FromStr>(properties: &Vec<TiledPropery>, name: &str) -> Option<T> {
+    properties
+        .iter()
+        .find(|e| e.name == name)
+        .and_then(|e| e.value.parse().ok())
+}
+
 fn get_z_from_layer_name(name: &str) -> usize {
     match name {
         "Structures" => 0,
         "Entities" => 1,
         "Interactives" => 2,
         "Background" => 3,
+        "Secrets" => 4,
         _ => 99,
     }
 }
@@ -200,6 +642,22 @@ pub struct TiledLevel {
     pub width: u32,
     pub layers: Vec<TiledLayer>,
     pub properties: Vec<TiledPropery>,
+    #[serde(default)]
+    pub tilesets: Vec<TiledTileset>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TiledTileset {
+    pub firstgid: usize,
+    #[serde(default)]
+    pub tiles: Vec<TiledTile>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TiledTile {
+    pub id: usize,
+    #[serde(default)]
+    pub properties: Vec<TiledPropery>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -213,16 +671,131 @@ pub struct TiledLayer {
     data: Option<Vec<usize>>,
     name: String,
     objects: Option<Vec<TiledEntity>>,
+    #[serde(default)]
+    properties: Vec<TiledPropery>,
+    /// Only visible layers are exported into the baked level image.
+    #[serde(default = "visible_by_default")]
+    visible: bool,
+}
+
+fn visible_by_default() -> bool {
+    true
 }
 
 #[derive(Debug, Deserialize)]
 pub struct TiledEntity {
     #[serde(rename(deserialize = "type"))]
     pub data_type: DataType,
+    #[serde(default)]
     pub properties: Vec<TiledPropery>,
+    #[serde(default)]
+    pub x: f32,
+    #[serde(default)]
+    pub y: f32,
+    #[serde(default)]
+    pub width: f32,
+    #[serde(default)]
+    pub height: f32,
+    pub polyline: Option<Vec<TiledPoint>>,
+    pub polygon: Option<Vec<TiledPoint>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TiledPoint {
+    pub x: f32,
+    pub y: f32,
 }
 
 #[derive(Debug, Deserialize)]
 pub enum DataType {
     BladeSaw,
+    Turret,
+    MissileLauncher,
+    Canon,
+    BladeSawPath,
+    PlatformPath,
+    DronePath,
+    WindZone,
+    GravityWell,
+    LowGravityZone,
+    Checkpoint,
+    RefuelPad,
+    RepairPad,
+    DeliveryPad,
+    Cargo,
+    Pad,
+    Target,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::from_str;
+
+    /// A 2x2 level with a moving platform and the given extra entities.
+    fn level(platform_properties: &str, entities: &str) -> LevelConfig {
+        let json = format!(
+            r#"{{
+                "height": 2,
+                "width": 2,
+                "properties": [
+                    {{"name": "start_x", "value": "0"}},
+                    {{"name": "start_y", "value": "0"}},
+                    {{"name": "text", "value": ""}}
+                ],
+                "layers": [{{
+                    "name": "Entities",
+                    "objects": [
+                        {{
+                            "type": "PlatformPath",
+                            "x": 0, "y": 32,
+                            "polyline": [{{"x": 0, "y": 0}}, {{"x": 32, "y": 0}}],
+                            "properties": [{}]
+                        }}{}
+                    ]
+                }}]
+            }}"#,
+            platform_properties, entities
+        );
+        LevelConfig::new(from_str(&json).unwrap())
+    }
+
+    fn platform_kind(config: &LevelConfig) -> PadKind {
+        match config.paths[0].kind {
+            PathKind::Platform(kind) => kind,
+            _ => panic!("expected a moving platform"),
+        }
+    }
+
+    const REFUEL_PAD: &str = r#", {"type": "RefuelPad", "x": 32, "y": 32}"#;
+
+    #[test]
+    fn moving_platforms_refuel_by_default() {
+        let config = level("", REFUEL_PAD);
+        let kind = platform_kind(&config);
+        assert_eq!(
+            kind,
+            PadKind::Refuel {
+                rate: DEFAULT_REFUEL_RATE
+            }
+        );
+        assert_eq!(config.refuel_rate(kind), Some(DEFAULT_REFUEL_RATE));
+    }
+
+    #[test]
+    fn moving_platforms_read_their_pad_kind_and_rate() {
+        let config = level(r#"{"name": "rate", "value": "40"}"#, REFUEL_PAD);
+        assert_eq!(platform_kind(&config), PadKind::Refuel { rate: 40. });
+
+        let config = level(r#"{"name": "pad", "value": "Plain"}"#, REFUEL_PAD);
+        assert_eq!(platform_kind(&config), PadKind::Plain);
+        assert_eq!(config.refuel_rate(PadKind::Plain), None);
+    }
+
+    #[test]
+    fn refuelling_platforms_count_as_refuel_pads() {
+        let config = level("", r#", {"type": "Pad", "x": 32, "y": 32}"#);
+        assert!(config.has_refuel_pads());
+        assert_eq!(config.refuel_rate(PadKind::Plain), None);
+    }
 }
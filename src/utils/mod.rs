@@ -1,15 +1,31 @@
+use serde::Deserialize;
+use std::f32::consts::PI;
+
+pub mod high_scores;
+pub mod leaderboard;
 pub mod level_reader;
 pub mod save;
 pub mod sound;
 pub mod sprites;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Point2D {
     pub x: f32,
     pub y: f32,
 }
 
-#[derive(Copy, Clone, Debug)]
+impl Point2D {
+    pub fn rotate_around(&self, center: &Point2D, angle: f32) -> Point2D {
+        let (sin, cos) = angle.sin_cos();
+        let (dx, dy) = (self.x - center.x, self.y - center.y);
+        Point2D {
+            x: center.x + dx * cos - dy * sin,
+            y: center.y + dx * sin + dy * cos,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Deserialize)]
 pub enum Direction {
     Left,
     Right,
@@ -17,16 +33,41 @@ pub enum Direction {
     Bottom,
 }
 
+impl Direction {
+    pub fn to_angle(&self) -> f32 {
+        match self {
+            Direction::Left => PI,
+            Direction::Right => 0.,
+            Direction::Top => PI / 2.,
+            Direction::Bottom => -PI / 2.,
+        }
+    }
+}
+
 pub fn distance_between_two_points(xa: f32, ya: f32, xb: f32, yb: f32) -> f32 {
     ((xa - xb) * (xa - xb) + (ya - yb) * (ya - yb)).sqrt()
 }
 
-pub fn min_of_f32_vec(mut list: [f32; 4]) -> f32 {
-    list.sort_by(|a, b| a.partial_cmp(b).unwrap());
-    list[0]
+pub fn angle_between_two_points(xa: f32, ya: f32, xb: f32, yb: f32) -> f32 {
+    (yb - ya).atan2(xb - xa)
+}
+
+pub fn angle_difference(from: f32, to: f32) -> f32 {
+    let mut diff = to - from;
+    while diff > PI {
+        diff -= 2. * PI;
+    }
+    while diff < -PI {
+        diff += 2. * PI;
+    }
+    diff
 }
 
-pub fn max_of_f32_vec(mut list: [f32; 4]) -> f32 {
-    list.sort_by(|a, b| b.partial_cmp(a).unwrap());
-    list[0]
+pub fn rotate_towards(current: f32, target: f32, max_step: f32) -> f32 {
+    let diff = angle_difference(current, target);
+    if diff.abs() <= max_step {
+        current + diff
+    } else {
+        current + max_step * diff.signum()
+    }
 }
@@ -1,7 +1,10 @@
+use crate::resources::campaign::Campaign;
+use crate::resources::difficulty::Difficulty;
 use amethyst::utils::application_root_dir;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::{from_reader, to_string_pretty};
+use std::collections::HashMap;
 use std::fs;
 use std::fs::File;
 use std::io::{BufReader, Write};
@@ -11,6 +14,12 @@ const SAVE_PATH: &str = "assets/.starlight";
 #[derive(Deserialize, Serialize, Debug)]
 pub struct StarlightSave {
     pub save: usize,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub campaign: Campaign,
+    #[serde(default)]
+    pub best_scores: HashMap<usize, u32>,
 }
 
 pub fn read_saved_level() -> Option<StarlightSave> {
@@ -31,14 +40,43 @@ pub fn read_saved_level() -> Option<StarlightSave> {
     None
 }
 
-pub fn save_progress(level_number: usize) {
+pub fn save_progress(level_number: usize, difficulty: Difficulty, campaign: Campaign) {
+    let best_scores = read_saved_level()
+        .map(|save| save.best_scores)
+        .unwrap_or_default();
+    write_save(&StarlightSave {
+        save: level_number,
+        difficulty,
+        campaign,
+        best_scores,
+    });
+}
+
+/// Returns true when `score` beats the best score stored for `level_number`.
+pub fn record_best_score(level_number: usize, score: u32) -> bool {
+    let mut save = read_saved_level().unwrap_or(StarlightSave {
+        save: 0,
+        difficulty: Difficulty::default(),
+        campaign: Campaign::default(),
+        best_scores: HashMap::new(),
+    });
+    let best = save.best_scores.entry(level_number).or_insert(0);
+    if score <= *best {
+        return false;
+    }
+    *best = score;
+    write_save(&save);
+    true
+}
+
+fn write_save(to_save: &StarlightSave) {
     let app_root = application_root_dir().unwrap();
     let input_path = app_root.join(SAVE_PATH);
     let path = Path::new(&input_path);
     let file_already_exist = path.exists();
     if !file_already_exist || fs::remove_file(path).is_ok() {
         if let Ok(mut target_file) = File::create(path) {
-            let to_save = to_string_pretty(&StarlightSave { save: level_number }).unwrap();
+            let to_save = to_string_pretty(to_save).unwrap();
             target_file.write_all(to_save.to_string().as_bytes());
         }
     }
@@ -102,3 +102,21 @@ pub fn play_air(sounds: &Sounds, storage: &AssetStorage<Source>, output: Option<
         }
     }
 }
+
+/// There is no dedicated warning sample, the hit sound is replayed quietly.
+pub fn play_low_fuel(sounds: &Sounds, storage: &AssetStorage<Source>, output: Option<&Output>) {
+    if let Some(ref output) = output.as_ref() {
+        if let Some(sound) = storage.get(&sounds.hit) {
+            output.play_once(sound, 0.3);
+        }
+    }
+}
+
+/// There is no dedicated refuel sample either, the air hiss stands in for it.
+pub fn play_refuel(sounds: &Sounds, storage: &AssetStorage<Source>, output: Option<&Output>) {
+    if let Some(ref output) = output.as_ref() {
+        if let Some(sound) = storage.get(&sounds.air) {
+            output.play_once(sound, 0.3);
+        }
+    }
+}
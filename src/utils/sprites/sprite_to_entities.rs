@@ -4,6 +4,7 @@ use crate::entities::collision::{Collider, Colliders};
 use crate::utils::sprites::plasma_doors::*;
 use crate::utils::sprites::TILE_SIZE;
 use crate::utils::{Direction, Point2D};
+use std::f32::consts::PI;
 
 pub fn sprite_to_colliders(sprite_nb: usize, pos_x: f32, pos_y: f32) -> Option<Colliders> {
     match sprite_nb {
@@ -465,17 +466,32 @@ pub fn sprite_to_colliders(sprite_nb: usize, pos_x: f32, pos_y: f32) -> Option<C
     None
 }
 
-pub fn init_bullet_collider(kind: &CanonKind, x: f32, y: f32) -> Colliders {
-    match kind {
-        CanonKind::Air => Colliders::from_vec(vec![Collider::new(Point2D { x, y }, 12., -28.)]),
-        _ => Colliders::from_vec(vec![Collider::new(
+pub fn init_bullet_collider(kind: &CanonKind, x: f32, y: f32, rotation: f32) -> Colliders {
+    let collider = match kind {
+        CanonKind::Air => Collider::new(Point2D { x, y }, 12., -28.),
+        _ => Collider::new(
             Point2D {
                 x: x + 14.,
                 y: y - 16.,
             },
             6.,
             -4.,
-        )]),
+        ),
+    };
+    Colliders::from_rotated_collider(collider, &Point2D { x, y }, rotation)
+}
+
+pub fn bullet_sprite_and_rotation(kind: &CanonKind, angle: f32) -> (usize, f32) {
+    match kind {
+        CanonKind::Air => {
+            if angle.cos() < 0. {
+                (2, angle - PI)
+            } else {
+                (3, angle)
+            }
+        }
+        CanonKind::Missile => (1, angle),
+        _ => (0, angle),
     }
 }
 
@@ -501,6 +517,36 @@ pub fn init_blade_saw_collider(x: f32, y: f32) -> Colliders {
     )])
 }
 
+pub fn init_mounted_canon_collider(x: f32, y: f32) -> Colliders {
+    Colliders::from_vec(vec![Collider::new(
+        Point2D {
+            x: x + 6.,
+            y: y - 6.,
+        },
+        20.,
+        -20.,
+    )])
+}
+
+pub fn turret_kind_to_sprite(kind: &CanonKind) -> usize {
+    match kind {
+        CanonKind::Missile => CANON_1_TO_RIGHT,
+        _ => CANON_2_TO_RIGHT,
+    }
+}
+
+pub fn canon_kind_to_sprite(kind: &CanonKind) -> usize {
+    match kind {
+        CanonKind::Smg => CANON_2_TO_RIGHT,
+        CanonKind::Air => CANON_3_TO_RIGHT,
+        _ => CANON_1_TO_RIGHT,
+    }
+}
+
+pub fn init_pad_collider(x: f32, y: f32) -> Colliders {
+    Colliders::from_vec(vec![Collider::new(Point2D { x, y }, 64., -32.)])
+}
+
 pub fn is_landing_platform_start(sprite_nb: usize) -> bool {
     sprite_nb == LANDING_PLATFORM || sprite_nb == STARTING_PLATFORM
 }
@@ -521,61 +567,61 @@ pub fn sprite_to_bonus_kind(sprite_nb: usize) -> Option<BonusKind> {
 pub fn sprite_to_canon(sprite_nb: usize, x: usize, y: usize) -> Option<Canon> {
     match sprite_nb {
         CANON_1_TO_LEFT => Some(Canon {
-            direction: Direction::Left,
+            angle: Direction::Left.to_angle(),
             kind: CanonKind::Bullet,
             bullet_x_start: (x as f32 * TILE_SIZE) - 16.,
             bullet_y_start: (y as f32 * TILE_SIZE) - 2.,
         }),
         CANON_1_TO_RIGHT => Some(Canon {
-            direction: Direction::Right,
+            angle: Direction::Right.to_angle(),
             kind: CanonKind::Bullet,
             bullet_x_start: (x as f32 * TILE_SIZE) + 16.,
             bullet_y_start: (y as f32 * TILE_SIZE) - 2.,
         }),
         CANON_1_TO_TOP => Some(Canon {
-            direction: Direction::Top,
+            angle: Direction::Top.to_angle(),
             kind: CanonKind::Bullet,
             bullet_x_start: (x as f32 * TILE_SIZE),
             bullet_y_start: (y as f32 * TILE_SIZE),
         }),
         CANON_1_TO_BOTTOM => Some(Canon {
-            direction: Direction::Bottom,
+            angle: Direction::Bottom.to_angle(),
             kind: CanonKind::Bullet,
             bullet_x_start: (x as f32 * TILE_SIZE),
             bullet_y_start: (y as f32 * TILE_SIZE),
         }),
         CANON_2_TO_LEFT => Some(Canon {
-            direction: Direction::Left,
+            angle: Direction::Left.to_angle(),
             kind: CanonKind::Smg,
             bullet_x_start: (x as f32 * TILE_SIZE) - 16.,
             bullet_y_start: (y as f32 * TILE_SIZE) - 1.,
         }),
         CANON_2_TO_RIGHT => Some(Canon {
-            direction: Direction::Right,
+            angle: Direction::Right.to_angle(),
             kind: CanonKind::Smg,
             bullet_x_start: (x as f32 * TILE_SIZE) + 16.,
             bullet_y_start: (y as f32 * TILE_SIZE) - 1.,
         }),
         CANON_2_TO_TOP => Some(Canon {
-            direction: Direction::Top,
+            angle: Direction::Top.to_angle(),
             kind: CanonKind::Smg,
             bullet_x_start: (x as f32 * TILE_SIZE),
             bullet_y_start: (y as f32 * TILE_SIZE) - 1.,
         }),
         CANON_2_TO_BOTTOM => Some(Canon {
-            direction: Direction::Bottom,
+            angle: Direction::Bottom.to_angle(),
             kind: CanonKind::Smg,
             bullet_x_start: (x as f32 * TILE_SIZE),
             bullet_y_start: (y as f32 * TILE_SIZE) - 1.,
         }),
         CANON_3_TO_LEFT => Some(Canon {
-            direction: Direction::Left,
+            angle: Direction::Left.to_angle(),
             kind: CanonKind::Air,
             bullet_x_start: (x as f32 * TILE_SIZE) - 16.,
             bullet_y_start: (y as f32 * TILE_SIZE) - 1.,
         }),
         CANON_3_TO_RIGHT => Some(Canon {
-            direction: Direction::Right,
+            angle: Direction::Right.to_angle(),
             kind: CanonKind::Air,
             bullet_x_start: (x as f32 * TILE_SIZE) + 16.,
             bullet_y_start: (y as f32 * TILE_SIZE) - 1.,
@@ -613,12 +659,21 @@ const CANON_3_TO_LEFT: usize = 48;
 const CANON_3_TO_RIGHT: usize = 47;
 
 const LANDING_PLATFORM: usize = 90;
-const STARTING_PLATFORM: usize = 92;
+// Two-tile pad drawn for moving platforms, checkpoints and object pads.
+pub const PAD_LEFT: usize = 92;
+pub const PAD_RIGHT: usize = 93;
+const STARTING_PLATFORM: usize = PAD_LEFT;
 pub const BLADE_SAW_SPRITE: usize = 16;
 
 const WRENCH: usize = 99;
 const FUEL: usize = 98;
 pub const COIN: usize = 97;
+// Placeholder art: main.png has no cargo pod or target frames yet, so both
+// reuse the fuel canister and only their tint (`CARGO_TINT`, `TARGET_TINT`)
+// tells them apart from a fuel bonus. Point these at their own frames once
+// the tileset has them.
+pub const CARGO: usize = FUEL;
+pub const TARGET: usize = FUEL;
 
 const HORIZONTAL_PILLAR_BASEMENT_LEFT: usize = 110;
 const HORIZONTAL_PILLAR_MIDDLE: usize = 111;
@@ -635,3 +690,38 @@ const VERTICAL_PILLAR_CLOSURE_OPENING: usize = 151;
 const VERTICAL_PILLAR_BASEMENT_BOTTOM: usize = 162;
 const VERTICAL_PILLAR_SLIDING_TOP: usize = 140;
 const VERTICAL_PILLAR_SLIDING_BOTTOM: usize = 150;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::canons::Bullet;
+    use crate::entities::collision::Aabb;
+
+    const EPSILON: f32 = 0.001;
+
+    fn assert_box(actual: &Aabb, expected: Aabb) {
+        assert!(
+            (actual.min_x - expected.min_x).abs() < EPSILON
+                && (actual.min_y - expected.min_y).abs() < EPSILON
+                && (actual.max_x - expected.max_x).abs() < EPSILON
+                && (actual.max_y - expected.max_y).abs() < EPSILON,
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn left_air_collider_stays_on_the_baseline() {
+        let bullet = Bullet::new(&CanonKind::Air, PI, 1.);
+        let colliders = init_bullet_collider(&bullet.kind, 64., 96., bullet.rotation);
+        assert_box(colliders.aabb(), Aabb::new(64., 68., 76., 96.));
+    }
+
+    #[test]
+    fn right_air_collider_stays_on_the_baseline() {
+        let bullet = Bullet::new(&CanonKind::Air, 0., 1.);
+        let colliders = init_bullet_collider(&bullet.kind, 64., 96., bullet.rotation);
+        assert_box(colliders.aabb(), Aabb::new(64., 68., 76., 96.));
+    }
+}